@@ -4,13 +4,19 @@ use crate::models::{self, BookingStatus};
 
 type DbError = Box<dyn std::error::Error + Send + Sync>;
 
-pub fn insert_new_user(conn: &mut PgConnection, nm: &str, topics: &Vec<String>) -> Result<models::User, DbError> {
+// Hard cap on conferences generated from a single RRULE expansion, so a
+// rule with a far-off bound (e.g. `FREQ=DAILY` with an UNTIL years away)
+// can't flood the `conferences` table in one request.
+const MAX_RECURRING_INSTANCES: usize = 100;
+
+pub fn insert_new_user(conn: &mut PgConnection, nm: &str, email: Option<&str>, topics: &Vec<String>) -> Result<models::User, DbError> {
     use crate::schema::{users::dsl::*, user_interests::dsl::user_id as tuid, user_interests::dsl::{topic, user_interests}};
-    
+
     // Use transaction to ensure user and topics are created atomically
     conn.transaction(|conn| {
         let new_user = models::User {
-            user_id: nm.to_owned()
+            user_id: nm.to_owned(),
+            email: email.map(str::to_owned),
         };
 
         diesel::insert_into(users).values(&new_user).execute(conn)?;
@@ -25,57 +31,344 @@ pub fn insert_new_user(conn: &mut PgConnection, nm: &str, topics: &Vec<String>)
     })
 }
 
-pub fn create_new_conference(conn: &mut PgConnection, form: &models::NewConference) -> Result<models::Conference, DbError> {
+// Creates one conference row per occurrence of `form`. When `form.rrule` is
+// absent this is just the one original occurrence; when present, `form.start`
+// is treated as the DTSTART of the first occurrence and `form.rrule` is
+// expanded (see `crate::rrule`) into the rest, each sharing `form`'s duration,
+// location and topics. Occurrences are named `"{name}"`, `"{name} #2"`, ...
+// so the `conferences.name` uniqueness constraint still holds across a family.
+pub fn create_recurring_conferences(conn: &mut PgConnection, form: &models::NewConference, max_topics: i32) -> Result<Vec<models::Conference>, DbError> {
     use crate::schema::{conferences::dsl::*, conference_topics::dsl::conference_id as cuid, conference_topics::dsl::{topic, conference_topics}};
-    
+
     let start_time = NaiveDateTime::parse_from_str(&form.start, "%Y-%m-%d %H:%M:%S")?;
     let end_time = NaiveDateTime::parse_from_str(&form.end, "%Y-%m-%d %H:%M:%S")?;
-    
+
     // Validate business rules
     if start_time >= end_time {
         return Err("Start timestamp must be before end timestamp".into());
     }
-    
+
     let duration = end_time.signed_duration_since(start_time);
     if duration > Duration::hours(12) {
         return Err("Duration should not exceed 12 hours".into());
     }
-    
+
     if form.slots <= 0 {
         return Err("Available slots must be greater than 0".into());
     }
-    
-    if form.topics.len() > 10 {
-        return Err("Maximum 10 topics allowed".into());
+
+    if form.topics.len() > max_topics as usize {
+        return Err(format!("Maximum {} topics allowed", max_topics).into());
     }
-    
-    // Use transaction to ensure conference and topics are created atomically
+
+    for resource in &form.resources {
+        if resource.granularity_minutes <= 0 {
+            return Err("Resource granularity_minutes must be greater than 0".into());
+        }
+        if resource.capacity <= 0 {
+            return Err("Resource capacity must be greater than 0".into());
+        }
+    }
+
+    let occurrence_starts = match &form.rrule {
+        Some(rule) => {
+            let occurrences = crate::rrule::expand_occurrences(rule, start_time, MAX_RECURRING_INSTANCES)?;
+
+            // Skip occurrences that have already started - nothing would ever
+            // be bookable for them. Only applies to the expanded occurrences
+            // of an RRULE; a plain single-occurrence create below is trusted
+            // as-is, the same way it always has been.
+            let now = Utc::now().naive_utc();
+            let occurrences: Vec<NaiveDateTime> = occurrences.into_iter().filter(|s| *s >= now).collect();
+
+            if occurrences.is_empty() {
+                return Err("Recurrence rule produced no occurrences that haven't already started".into());
+            }
+
+            occurrences
+        }
+        None => vec![start_time],
+    };
+
+    // Use transaction to ensure every occurrence and its topics are created atomically
     conn.transaction(|conn| {
-        let new_conf = models::NewConferenceInternal {
-            name: form.name.clone(),
-            location: form.location.clone(),
-            start_timestamp: start_time,
-            end_timestamp: end_time,
-            total_slots: form.slots,
-            available_slots: form.slots
-        };
+        let mut created = Vec::with_capacity(occurrence_starts.len());
 
-        let id: i32 = diesel::insert_into(conferences).values(&new_conf).returning(conference_id).get_result(conn)?;
+        for (index, occ_start) in occurrence_starts.iter().enumerate() {
+            let occ_end = *occ_start + duration;
+            let occ_name = if index == 0 { form.name.clone() } else { format!("{} #{}", form.name, index + 1) };
 
-        let topics = form.topics.iter().map(|t| {
-            (cuid.eq(id), topic.eq(t))
-        }).collect::<Vec<_>>();
-        diesel::insert_into(conference_topics).values(&topics).execute(conn)?;
+            let new_conf = models::NewConferenceInternal {
+                name: occ_name,
+                location: form.location.clone(),
+                start_timestamp: *occ_start,
+                end_timestamp: occ_end,
+                total_slots: form.slots,
+                available_slots: form.slots
+            };
+
+            let id: i32 = diesel::insert_into(conferences).values(&new_conf).returning(conference_id).get_result(conn)?;
+
+            let topics = form.topics.iter().map(|t| {
+                (cuid.eq(id), topic.eq(t))
+            }).collect::<Vec<_>>();
+            diesel::insert_into(conference_topics).values(&topics).execute(conn)?;
+
+            if !form.resources.is_empty() {
+                create_resources(conn, id, &form.resources)?;
+            }
+
+            created.push(conferences.find(id).first::<models::Conference>(conn)?);
+        }
+
+        Ok(created)
+    })
+}
+
+// Creates one `resources` row per entry of `new_resources` under `conf_id`,
+// for `create_recurring_conferences`/`NewConference::resources` - every
+// occurrence of a recurring conference gets its own copy of the same
+// resource definitions, the way it already gets its own copy of `form.topics`.
+pub fn create_resources(
+    conn: &mut PgConnection,
+    conf_id: i32,
+    new_resources: &[models::NewResource],
+) -> Result<Vec<models::Resource>, DbError> {
+    use crate::schema::resources;
+
+    let rows: Vec<models::NewResourceInternal> = new_resources
+        .iter()
+        .map(|r| models::NewResourceInternal {
+            conference_id: conf_id,
+            name: r.name.clone(),
+            granularity_minutes: r.granularity_minutes,
+            capacity: r.capacity,
+        })
+        .collect();
+
+    let created = diesel::insert_into(resources::table)
+        .values(&rows)
+        .get_results::<models::Resource>(conn)?;
+
+    Ok(created)
+}
+
+pub fn get_resource_by_name(conn: &mut PgConnection, conf_id: i32, resource_name: &str) -> Result<models::Resource, DbError> {
+    use crate::schema::resources::dsl::{resources, conference_id, name};
+
+    let resource = resources
+        .filter(conference_id.eq(conf_id))
+        .filter(name.eq(resource_name))
+        .first::<models::Resource>(conn)?;
+
+    Ok(resource)
+}
+
+// Buckets `conference`'s `[start_timestamp, end_timestamp)` window into
+// `resource.granularity_minutes`-long slots and counts how many CONFIRMED or
+// ConfirmationPending bookings (a pending confirmation still holds the
+// place, same occupancy rule `create_booking_atomic` applies at the
+// whole-conference level) already occupy each one.
+pub fn get_resource_slot_availability(
+    conn: &mut PgConnection,
+    conference: &models::Conference,
+    resource: &models::Resource,
+) -> Result<Vec<models::SlotAvailability>, DbError> {
+    use crate::schema::bookings::dsl::{bookings, resource_id as bookings_resource_id, slot_start as bookings_slot_start, status};
+
+    let occupied_rows: Vec<(Option<NaiveDateTime>, i64)> = bookings
+        .filter(bookings_resource_id.eq(resource.resource_id))
+        .filter(status.eq_any([BookingStatus::CONFIRMED, BookingStatus::ConfirmationPending]))
+        .group_by(bookings_slot_start)
+        .select((bookings_slot_start, diesel::dsl::count_star()))
+        .load(conn)?;
+
+    let occupied: std::collections::HashMap<NaiveDateTime, i32> = occupied_rows
+        .into_iter()
+        .filter_map(|(slot, count)| slot.map(|s| (s, count as i32)))
+        .collect();
+
+    let step = Duration::minutes(resource.granularity_minutes as i64);
+    let mut slots = Vec::new();
+    let mut slot_start = conference.start_timestamp;
+
+    while slot_start < conference.end_timestamp {
+        let booked = occupied.get(&slot_start).copied().unwrap_or(0);
+        slots.push(models::SlotAvailability {
+            hour: slot_start,
+            places_available: (resource.capacity - booked).max(0),
+            places_bookable: resource.capacity,
+        });
+        slot_start += step;
+    }
+
+    Ok(slots)
+}
+
+// Resource/slot-scoped counterpart to `create_booking_atomic`: locks the
+// resource row (not the conference row - a conference with resources
+// defined is booked per slot, never against `conferences.available_slots`)
+// and confirms immediately if the slot still has a free place, else
+// waitlists ordered by `waitlist_position` scoped to this
+// `(resource_id, slot_start)` the same way the whole-conference path orders
+// by `conference_id`.
+//
+// Waitlisted slot bookings are not auto-promoted the way
+// `WaitlistQueueService` promotes whole-conference ones - there is no
+// per-slot AMQP queue behind them yet, so a canceled slot booking simply
+// frees capacity for the next direct booking attempt rather than pushing a
+// waitlisted one forward automatically.
+pub fn create_resource_slot_booking_atomic(
+    conn: &mut PgConnection,
+    conference_id: i32,
+    resource: &models::Resource,
+    slot_start: NaiveDateTime,
+    uid: &str,
+) -> Result<models::Booking, DbError> {
+    use crate::schema::{
+        bookings::dsl::{
+            bookings, booking_id as booking_id_col, user_id as bookings_user_id,
+            resource_id as bookings_resource_id, slot_start as bookings_slot_start,
+            status, waitlist_position,
+        },
+        resources::dsl::{resources, resource_id as resource_id_col},
+    };
+
+    conn.transaction(|conn| {
+        // Lock the resource row so two concurrent bookings for the same slot
+        // can't both observe spare capacity.
+        resources
+            .filter(resource_id_col.eq(resource.resource_id))
+            .for_update()
+            .first::<models::Resource>(conn)?;
+
+        let existing_booking: Option<i32> = bookings
+            .filter(bookings_user_id.eq(uid))
+            .filter(bookings_resource_id.eq(resource.resource_id))
+            .filter(bookings_slot_start.eq(slot_start))
+            .filter(status.ne(BookingStatus::CANCELED))
+            .select(booking_id_col)
+            .for_update()
+            .first(conn)
+            .optional()?;
+
+        if existing_booking.is_some() {
+            return Err("User already has an active booking for this slot".into());
+        }
+
+        let occupied: i64 = bookings
+            .filter(bookings_resource_id.eq(resource.resource_id))
+            .filter(bookings_slot_start.eq(slot_start))
+            .filter(status.eq_any([BookingStatus::CONFIRMED, BookingStatus::ConfirmationPending]))
+            .count()
+            .get_result(conn)?;
+
+        let existing_waitlist: i64 = bookings
+            .filter(bookings_resource_id.eq(resource.resource_id))
+            .filter(bookings_slot_start.eq(slot_start))
+            .filter(status.eq(BookingStatus::WAITLISTED))
+            .count()
+            .get_result(conn)?;
+
+        if occupied < resource.capacity as i64 && existing_waitlist == 0 {
+            let new_booking = models::NewBooking {
+                conference_id,
+                user_id: uid.to_string(),
+                status: BookingStatus::CONFIRMED,
+                waitlist_position: None,
+                can_confirm: Some(false),
+                resource_id: Some(resource.resource_id),
+                slot_start: Some(slot_start),
+            };
+
+            let new_booking_id = diesel::insert_into(bookings)
+                .values(&new_booking)
+                .returning(booking_id_col)
+                .get_result::<i32>(conn)?;
 
-        // Retrieve the created conference
-        let created_conference = conferences
-            .find(id)
-            .first::<models::Conference>(conn)?;
+            Ok(bookings.find(new_booking_id).first::<models::Booking>(conn)?)
+        } else {
+            let max_position: Option<i32> = bookings
+                .filter(bookings_resource_id.eq(resource.resource_id))
+                .filter(bookings_slot_start.eq(slot_start))
+                .filter(status.eq(BookingStatus::WAITLISTED))
+                .select(diesel::dsl::max(waitlist_position))
+                .first(conn)?;
 
-        Ok(created_conference)
+            let new_booking = models::NewBooking {
+                conference_id,
+                user_id: uid.to_string(),
+                status: BookingStatus::WAITLISTED,
+                waitlist_position: Some(max_position.unwrap_or(0) + 1),
+                can_confirm: Some(false),
+                resource_id: Some(resource.resource_id),
+                slot_start: Some(slot_start),
+            };
+
+            let new_booking_id = diesel::insert_into(bookings)
+                .values(&new_booking)
+                .returning(booking_id_col)
+                .get_result::<i32>(conn)?;
+
+            Ok(bookings.find(new_booking_id).first::<models::Booking>(conn)?)
+        }
     })
 }
 
+// Ranks upcoming conferences for `uid` by how many of their `conference_topics`
+// overlap with the user's `user_interests`, descending by match count.
+// Conferences sharing none of the user's topics never appear in the result.
+pub fn get_recommended_conferences(conn: &mut PgConnection, uid: &str) -> Result<Vec<models::ConferenceRecommendation>, DbError> {
+    use crate::schema::{conference_topics, conferences, user_interests};
+
+    let interests: Vec<String> = user_interests::table
+        .filter(user_interests::user_id.eq(uid))
+        .select(user_interests::topic)
+        .load(conn)?;
+
+    if interests.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let now = Utc::now().naive_utc();
+
+    // Every (conference, topic) pair for an upcoming conference where the
+    // topic is one the user is interested in.
+    let matches: Vec<(models::Conference, String)> = conferences::table
+        .inner_join(conference_topics::table)
+        .filter(conferences::start_timestamp.gt(now))
+        .filter(conference_topics::topic.eq_any(&interests))
+        .select((conferences::all_columns, conference_topics::topic))
+        .load(conn)?;
+
+    let mut by_conference: std::collections::HashMap<i32, (models::Conference, Vec<String>)> = std::collections::HashMap::new();
+    for (conference, topic) in matches {
+        by_conference
+            .entry(conference.conference_id)
+            .or_insert_with(|| (conference, Vec::new()))
+            .1
+            .push(topic);
+    }
+
+    let mut recommendations: Vec<models::ConferenceRecommendation> = by_conference
+        .into_values()
+        .map(|(conference, matched_topics)| models::ConferenceRecommendation {
+            conference_id: conference.conference_id,
+            name: conference.name,
+            location: conference.location,
+            start_timestamp: conference.start_timestamp,
+            end_timestamp: conference.end_timestamp,
+            available_slots: conference.available_slots,
+            match_count: matched_topics.len() as i32,
+            matched_topics,
+        })
+        .collect();
+
+    recommendations.sort_by(|a, b| b.match_count.cmp(&a.match_count));
+
+    Ok(recommendations)
+}
+
 pub fn get_conference_by_name(conn: &mut PgConnection, conference_name: &str) -> Result<models::Conference, DbError> {
     use crate::schema::conferences::dsl::{conferences, name};
     
@@ -88,15 +381,30 @@ pub fn get_conference_by_name(conn: &mut PgConnection, conference_name: &str) ->
 
 pub fn get_user_by_id(conn: &mut PgConnection, uid: &str) -> Result<models::User, DbError> {
     use crate::schema::users::dsl::{users, user_id as users_user_id};
-    
+
     let user = users
         .filter(users_user_id.eq(uid))
         .select(models::User::as_select())
         .first::<models::User>(conn)?;
-    
+
     Ok(user)
 }
 
+// Resolves the address `notifier::SmtpNotifier` should send to for `uid`,
+// since `uid` itself is an alphanumeric username rather than an email. `Ok(None)`
+// means the user exists but hasn't set one, which the notifier treats the
+// same as "skip this notification" as a missing `user_id`.
+pub fn get_user_email(conn: &mut PgConnection, uid: &str) -> Result<Option<String>, DbError> {
+    use crate::schema::users::dsl::{users, user_id as users_user_id, email};
+
+    let email = users
+        .filter(users_user_id.eq(uid))
+        .select(email)
+        .first::<Option<String>>(conn)?;
+
+    Ok(email)
+}
+
 pub fn check_user_has_overlapping_booking(
     conn: &mut PgConnection, 
     user_id: &str, 
@@ -168,8 +476,10 @@ pub fn create_confirmed_booking(
             status: BookingStatus::CONFIRMED,
             waitlist_position: None,
             can_confirm: Some(false),
+            resource_id: None,
+            slot_start: None,
         };
-        
+
         let new_booking_id = diesel::insert_into(bookings)
             .values(&new_booking)
             .returning(booking_id)
@@ -212,6 +522,8 @@ pub fn create_waitlist_booking(
             status: BookingStatus::WAITLISTED,
             waitlist_position: Some(next_position),
             can_confirm: Some(false),
+        resource_id: None,
+        slot_start: None,
         };
         
         let new_booking_id = diesel::insert_into(bookings)
@@ -248,6 +560,64 @@ pub fn get_booking_with_conference_name(
     Ok((booking, conference_name))
 }
 
+// Every non-canceled booking for `uid`, paired with its conference, ordered
+// by the conference's start time - the feed `icalendar::render_user_calendar`
+// turns into one VEVENT per CONFIRMED or WAITLISTED row.
+pub fn get_user_calendar_bookings(conn: &mut PgConnection, uid: &str) -> Result<Vec<(models::Booking, models::Conference)>, DbError> {
+    use crate::schema::{bookings, conferences};
+
+    let rows: Vec<(models::Booking, models::Conference)> = bookings::table
+        .inner_join(conferences::table)
+        .filter(bookings::user_id.eq(uid))
+        .filter(bookings::status.ne(models::BookingStatus::CANCELED))
+        .order_by(conferences::start_timestamp.asc())
+        .select((bookings::all_columns, conferences::all_columns))
+        .load(conn)?;
+
+    Ok(rows)
+}
+
+// `ConfirmationPending` bookings whose deadline falls within `lead_secs`
+// from now and that haven't had a reminder sent yet (`reminder_sent_at IS
+// NULL`), paired with their conference's name.
+// `notifier::run_confirmation_reminder_scheduler` sends one reminder per row
+// and calls `mark_reminder_sent` right after so the next poll doesn't
+// re-notify it.
+pub fn get_bookings_needing_confirmation_reminder(
+    conn: &mut PgConnection,
+    lead_secs: i64,
+) -> Result<Vec<(models::Booking, String)>, DbError> {
+    use crate::schema::{bookings, conferences};
+
+    let now = Utc::now().naive_utc();
+    let horizon = now + Duration::seconds(lead_secs);
+
+    let rows: Vec<(models::Booking, String)> = bookings::table
+        .inner_join(conferences::table)
+        .filter(bookings::status.eq(BookingStatus::ConfirmationPending))
+        .filter(bookings::reminder_sent_at.is_null())
+        .filter(bookings::waitlist_confirmation_deadline.gt(now))
+        .filter(bookings::waitlist_confirmation_deadline.le(horizon))
+        .select((bookings::all_columns, conferences::name))
+        .load(conn)?;
+
+    Ok(rows)
+}
+
+// Marks `booking_id` as having had its confirmation-deadline reminder sent,
+// so it's excluded from `get_bookings_needing_confirmation_reminder` on the
+// scheduler's next poll.
+pub fn mark_reminder_sent(conn: &mut PgConnection, booking_id: i32) -> Result<(), DbError> {
+    use crate::schema::bookings::dsl::{bookings, booking_id as bookings_booking_id, reminder_sent_at};
+
+    diesel::update(bookings)
+        .filter(bookings_booking_id.eq(booking_id))
+        .set(reminder_sent_at.eq(Some(Utc::now().naive_utc())))
+        .execute(conn)?;
+
+    Ok(())
+}
+
 pub fn confirm_waitlist_booking(
     conn: &mut PgConnection,
     booking_id: i32
@@ -371,6 +741,35 @@ pub fn confirm_waitlist_booking_secure(
     })
 }
 
+// Ownership-checked counterpart to `cancel_booking`, mirroring
+// `confirm_waitlist_booking_secure` - `BookingIdRequest` carries no owner, so
+// the handler must verify the caller's token matches the booking before
+// canceling on their behalf.
+pub fn cancel_booking_secure(
+    conn: &mut PgConnection,
+    booking_id: i32,
+    user_id: &str
+) -> Result<models::Booking, DbError> {
+    use crate::schema::bookings::dsl::bookings;
+
+    conn.transaction(|conn| {
+        let booking = bookings.find(booking_id).first::<models::Booking>(conn)?;
+
+        match &booking.user_id {
+            Some(booking_user_id) if booking_user_id == user_id => {},
+            Some(booking_user_id) => {
+                return Err(format!("Access denied: booking {} belongs to user '{}', not '{}'",
+                                 booking_id, booking_user_id, user_id).into());
+            },
+            None => {
+                return Err("Booking has no associated user".into());
+            }
+        }
+
+        cancel_booking(conn, booking_id)
+    })
+}
+
 pub fn cancel_booking(conn: &mut PgConnection, booking_id: i32) -> Result<models::Booking, DbError> {
     use crate::schema::{
         bookings::dsl::{
@@ -468,9 +867,9 @@ pub fn remove_from_overlapping_waitlists(
     confirmed_conference_start: NaiveDateTime,
     confirmed_conference_end: NaiveDateTime,
     exclude_conference_id: i32
-) -> Result<(), DbError> {
+) -> Result<Vec<i32>, DbError> {
     use crate::schema::{bookings, conferences};
-    
+
     // Use transaction to prevent race conditions between SELECT and UPDATE
     conn.transaction(|conn| {
         // Find overlapping conferences where user is waitlisted
@@ -489,7 +888,7 @@ pub fn remove_from_overlapping_waitlists(
         // Cancel these waitlist bookings
         if !overlapping_bookings.is_empty() {
             diesel::update(bookings::table)
-                .filter(bookings::booking_id.eq_any(overlapping_bookings))
+                .filter(bookings::booking_id.eq_any(&overlapping_bookings))
                 .set((
                     bookings::status.eq(BookingStatus::CANCELED),
                     bookings::canceled_at.eq(Some(Utc::now().naive_utc())),
@@ -499,8 +898,8 @@ pub fn remove_from_overlapping_waitlists(
                 ))
                 .execute(conn)?;
         }
-        
-        Ok(())
+
+        Ok(overlapping_bookings)
     })
 }
 
@@ -604,6 +1003,8 @@ pub fn create_booking_atomic(
                 status: BookingStatus::CONFIRMED,
                 waitlist_position: None,
                 can_confirm: Some(false),
+            resource_id: None,
+            slot_start: None,
             };
             
             let new_booking_id = diesel::insert_into(bookings)
@@ -652,6 +1053,8 @@ fn create_waitlist_booking_internal(
         status: BookingStatus::WAITLISTED,
         waitlist_position: Some(next_position),
         can_confirm: Some(false),
+    resource_id: None,
+    slot_start: None,
     };
     
     let new_booking_id = diesel::insert_into(bookings)
@@ -0,0 +1,70 @@
+// JWT-backed authentication. Tokens are HS256, signed with
+// `Settings::jwt_secret`, carrying only `{ sub: user_id, exp: unix_ts }` -
+// there's no server-side session state, so verification is just "does the
+// signature check out and has `exp` not passed".
+use actix_web::{dev::Payload, error, web, FromRequest, HttpRequest};
+use chrono::{Duration, Utc};
+use futures_util::future::{ready, Ready};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::settings::Settings;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    sub: String,
+    exp: usize,
+}
+
+// Signs a token for `user_id`, valid for `settings.jwt_ttl_secs` from now.
+// Called on user registration and from `/login` to re-issue one.
+pub fn issue_token(settings: &Settings, user_id: &str) -> Result<String, jsonwebtoken::errors::Error> {
+    let exp = (Utc::now() + Duration::seconds(settings.jwt_ttl_secs)).timestamp() as usize;
+    let claims = Claims { sub: user_id.to_string(), exp };
+
+    encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(settings.jwt_secret.as_bytes()),
+    )
+}
+
+// The `user_id` carried by a verified `Authorization: Bearer <jwt>` header.
+// Handlers that used to trust a `user_id` field in the request body extract
+// this instead, so the acting user always comes from a signed token rather
+// than a value the caller could set to anyone they like.
+pub struct AuthenticatedUser(pub String);
+
+impl FromRequest for AuthenticatedUser {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let settings = match req.app_data::<web::Data<Settings>>() {
+            Some(settings) => settings,
+            None => return ready(Err(error::ErrorInternalServerError("settings not configured"))),
+        };
+
+        let token = req
+            .headers()
+            .get("Authorization")
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header| header.strip_prefix("Bearer "));
+
+        let token = match token {
+            Some(token) => token,
+            None => return ready(Err(error::ErrorUnauthorized("missing bearer token"))),
+        };
+
+        let decoded = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(settings.jwt_secret.as_bytes()),
+            &Validation::new(Algorithm::HS256),
+        );
+
+        match decoded {
+            Ok(data) => ready(Ok(AuthenticatedUser(data.claims.sub))),
+            Err(_) => ready(Err(error::ErrorUnauthorized("invalid or expired token"))),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use lru::LruCache;
+
+use crate::models::{Booking, Conference};
+
+// Process-local LRU cache for the conference-by-name and booking-by-id reads
+// that `book_conference`, `confirm_waitlist_booking`, `cancel_booking`, and
+// `get_booking_status` otherwise repeat against Postgres on every request.
+// Sized via `Settings::cache_capacity`.
+//
+// Every write path that can change a cached conference's `available_slots`
+// or a cached booking's `status`/`can_confirm`/`waitlist_position` must
+// invalidate (or update) the corresponding entry through this cache -
+// `book_conference`/`confirm_waitlist_booking`/`cancel_booking` in `main.rs`,
+// and `move_booking_to_waitlist_end`/`promote_next_waitlisted_person`/
+// `publish_slot_available` in `queue.rs` - so a hit here is never stale by
+// more than the gap between the DB write and the cache call.
+//
+// Cloning shares the same underlying cache (all fields are `Arc`), the same
+// way `WaitlistQueueService` is cloned to hand every worker/consumer its own
+// handle onto shared state.
+#[derive(Clone)]
+pub struct LookupCache {
+    conferences: Arc<Mutex<LruCache<String, Conference>>>,
+    bookings: Arc<Mutex<LruCache<i32, (Booking, String)>>>,
+}
+
+impl LookupCache {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::new(1).unwrap());
+        Self {
+            conferences: Arc::new(Mutex::new(LruCache::new(capacity))),
+            bookings: Arc::new(Mutex::new(LruCache::new(capacity))),
+        }
+    }
+
+    pub fn get_conference(&self, name: &str) -> Option<Conference> {
+        self.conferences.lock().unwrap().get(name).cloned()
+    }
+
+    pub fn put_conference(&self, conference: Conference) {
+        self.conferences.lock().unwrap().put(conference.name.clone(), conference);
+    }
+
+    pub fn invalidate_conference(&self, name: &str) {
+        self.conferences.lock().unwrap().pop(name);
+    }
+
+    pub fn get_booking(&self, booking_id: i32) -> Option<(Booking, String)> {
+        self.bookings.lock().unwrap().get(&booking_id).cloned()
+    }
+
+    pub fn put_booking(&self, booking_id: i32, entry: (Booking, String)) {
+        self.bookings.lock().unwrap().put(booking_id, entry);
+    }
+
+    pub fn invalidate_booking(&self, booking_id: i32) {
+        self.bookings.lock().unwrap().pop(&booking_id);
+    }
+}
@@ -0,0 +1,56 @@
+// Minimal RFC 5545 iCalendar rendering - just enough to cover the per-user
+// calendar feed's use case: one VEVENT per CONFIRMED or WAITLISTED booking,
+// with CONFIRMED bookings marked CONFIRMED and WAITLISTED bookings marked
+// TENTATIVE so a subscribed calendar app shows pending entries distinctly.
+// ConfirmationPending and CANCELED bookings get no VEVENT.
+use chrono::NaiveDateTime;
+
+use crate::models::{Booking, BookingStatus, Conference};
+
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+// Renders `bookings` (each paired with its conference, as returned by
+// `actions::get_user_calendar_bookings`) into a full VCALENDAR document.
+// Bookings whose status isn't CONFIRMED or WAITLISTED are skipped, since
+// there's nothing meaningful to put on a calendar for them yet.
+pub fn render_user_calendar(bookings: &[(Booking, Conference)]) -> String {
+    let mut ics = String::new();
+    ics.push_str("BEGIN:VCALENDAR\r\n");
+    ics.push_str("VERSION:2.0\r\n");
+    ics.push_str("PRODID:-//ampq-waitlist-api//booking calendar//EN\r\n");
+    ics.push_str("CALSCALE:GREGORIAN\r\n");
+
+    for (booking, conference) in bookings {
+        let status = match booking.status {
+            BookingStatus::CONFIRMED => "CONFIRMED",
+            BookingStatus::WAITLISTED => "TENTATIVE",
+            BookingStatus::CANCELED | BookingStatus::ConfirmationPending => continue,
+        };
+
+        ics.push_str("BEGIN:VEVENT\r\n");
+        ics.push_str(&format!("UID:booking-{}@ampq-waitlist-api\r\n", booking.booking_id));
+        ics.push_str(&format!("DTSTAMP:{}\r\n", format_date_time(conference.created_at.unwrap_or(conference.start_timestamp))));
+        ics.push_str(&format!("DTSTART:{}\r\n", format_date_time(conference.start_timestamp)));
+        ics.push_str(&format!("DTEND:{}\r\n", format_date_time(conference.end_timestamp)));
+        ics.push_str(&format!("SUMMARY:{}\r\n", escape_text(&conference.name)));
+        ics.push_str(&format!("LOCATION:{}\r\n", escape_text(&conference.location)));
+        ics.push_str(&format!("STATUS:{}\r\n", status));
+        ics.push_str("END:VEVENT\r\n");
+    }
+
+    ics.push_str("END:VCALENDAR\r\n");
+    ics
+}
+
+fn format_date_time(dt: NaiveDateTime) -> String {
+    dt.format(DATE_TIME_FORMAT).to_string()
+}
+
+// Escapes the characters RFC 5545 §3.3.11 requires escaping in TEXT values.
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
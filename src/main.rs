@@ -1,15 +1,26 @@
 #[macro_use]
 extern crate diesel;
 
-use actix_web::{error, middleware, post, get, web, App, HttpResponse, HttpServer, Responder};
+use actix_web::{error, middleware, post, get, web, App, HttpRequest, HttpResponse, HttpServer, Responder};
 use diesel::{prelude::*, r2d2};
 use regex::Regex;
 use chrono::{NaiveDateTime, Utc, DateTime};
 use dotenvy;
+use futures_util::{stream, StreamExt};
+use tokio::sync::broadcast;
 mod actions;
+mod auth;
+mod cache;
+mod icalendar;
+mod metrics;
 mod models;
+mod notifier;
+mod response;
 mod schema;
 mod queue;
+mod rrule;
+mod settings;
+mod telemetry;
 
 type DbPool = r2d2::Pool<r2d2::ConnectionManager<PgConnection>>;
 // Define DbError type for send + sync
@@ -30,10 +41,17 @@ struct ScheduleConferenceStartRequest {
     name: String,
 }
 
+// Richer topic-validation rule (mirrors what federated directory services
+// enforce): must start with a letter or digit, may otherwise contain letters,
+// digits, spaces, or dashes, and is capped at 35 characters total.
+const TOPIC_PATTERN: &str = r"^[a-zA-Z0-9][a-zA-Z0-9 -]{0,34}$";
+const TOPIC_VALIDATION_MESSAGE: &str = "Topics must start with a letter or digit, may contain only letters, digits, spaces, or dashes, and be at most 35 characters";
+
 #[post("/conference")]
 async fn add_conference(
-    pool: web::Data<DbPool>, 
+    pool: web::Data<DbPool>,
     queue_service: web::Data<queue::WaitlistQueueService>,
+    settings: web::Data<settings::Settings>,
     form: web::Json<models::NewConference>
 ) -> actix_web::Result<impl Responder> {
     let re = Regex::new(r"^[a-zA-Z0-9 ]*$").unwrap();    
@@ -49,17 +67,21 @@ async fn add_conference(
         return Ok(HttpResponse::BadRequest().json(Res { message: "At least one topic is required".to_string() }));
     }
     
-    if form.topics.len() > 10 {
-        return Ok(HttpResponse::BadRequest().json(Res { message: "Maximum 10 topics allowed".to_string() }));
+    if form.topics.len() > settings.max_conference_topics as usize {
+        return Ok(HttpResponse::BadRequest().json(Res { message: format!("Maximum {} topics allowed", settings.max_conference_topics) }));
     }
-    
+
+    let topic_re = Regex::new(TOPIC_PATTERN).unwrap();
     for topic in &form.topics {
-        if re.captures(topic).is_none() {
-            return Ok(HttpResponse::BadRequest().json(Res { message: "Topics should be Alphanumeric with spaces allowed".to_string() }));
+        if topic_re.captures(topic).is_none() {
+            return Ok(HttpResponse::BadRequest().json(Res { message: TOPIC_VALIDATION_MESSAGE.to_string() }));
         }
     }
-    
-    let start_time = match NaiveDateTime::parse_from_str(&form.start, "%Y-%m-%d %H:%M:%S") {
+
+    // Business-rule validation (ordering, duration, RRULE expansion) happens
+    // in `actions::create_recurring_conferences` - this is just an early,
+    // cheap format check so a malformed timestamp doesn't reach `web::block`.
+    let _start_time = match NaiveDateTime::parse_from_str(&form.start, "%Y-%m-%d %H:%M:%S") {
         Ok(dt) => dt,
         Err(_) => return Ok(HttpResponse::BadRequest().json(Res { message: "start timestamp not in correct format".to_string() }))
     };
@@ -69,15 +91,16 @@ async fn add_conference(
         _ => ()
     }
 
-    let conference = web::block(move || {
+    let max_conference_topics = settings.max_conference_topics;
+    let conferences = web::block(move || {
         let mut conn = pool.get()?;
-        actions::create_new_conference(&mut conn, &form)
+        actions::create_recurring_conferences(&mut conn, &form, max_conference_topics)
     })
     .await?
     .map_err(|e| {
         let detail = e.to_string();
         log::error!("Failed to add conference: {:?}", e);
-        
+
         if let Some(diesel_error) = e.downcast_ref::<diesel::result::Error>() {
             match diesel_error {
                 diesel::result::Error::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _) => {
@@ -99,50 +122,53 @@ async fn add_conference(
         }
     })?;
 
-    // Schedule conference start event for queue cleanup
-    let conference_name = conference.name.clone();
-    let start_time_utc = DateTime::<Utc>::from_naive_utc_and_offset(start_time, Utc);
-    let queue_service_clone = queue_service.clone();
-    
-    tokio::spawn(async move {
-        if let Err(e) = queue_service_clone.schedule_conference_start_event(&conference_name, start_time_utc).await {
-            log::error!("Failed to schedule conference start event for '{}': {:?}", conference_name, e);
-        }
-    });
+    // Schedule a conference-start cleanup event for every generated occurrence.
+    for conference in &conferences {
+        let conference_name = conference.name.clone();
+        let start_time_utc = DateTime::<Utc>::from_naive_utc_and_offset(conference.start_timestamp, Utc);
+        let queue_service_clone = queue_service.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = queue_service_clone.schedule_conference_start_event(&conference_name, start_time_utc).await {
+                log::error!("Failed to schedule conference start event for '{}': {:?}", conference_name, e);
+            }
+        });
+    }
 
-    Ok(HttpResponse::Created().json(Res { message: "conference added successfully".to_string() }))
+    let conference_ids = conferences.iter().map(|c| c.conference_id).collect();
+    Ok(HttpResponse::Created().json(models::CreateConferencesResponse { conference_ids }))
 }
 
 #[post("/user")]
-async fn add_user(pool: web::Data<DbPool>, form: web::Json<models::NewUser>) -> actix_web::Result<impl Responder> {
+async fn add_user(pool: web::Data<DbPool>, settings: web::Data<settings::Settings>, form: web::Json<models::NewUser>) -> actix_web::Result<impl Responder> {
     let re = Regex::new(r"^[a-zA-Z0-9]*$").unwrap();
-    let topic_re = Regex::new(r"^[a-zA-Z0-9 ]*$").unwrap();
-    
+    let topic_re = Regex::new(TOPIC_PATTERN).unwrap();
+
     if re.captures(&form.user_id).is_none() {
         return Ok(HttpResponse::BadRequest().json(Res { message: "UserID should be Alphanumeric with no special characters".to_string() }));
     }
-    
+
     if form.topics.is_empty() {
         return Ok(HttpResponse::BadRequest().json(Res { message: "topics are required".to_string() }));
-    } else if form.topics.len() > 50 {
-        return Ok(HttpResponse::BadRequest().json(Res { message: "max 50 topics allowed".to_string() }));
+    } else if form.topics.len() > settings.max_user_topics as usize {
+        return Ok(HttpResponse::BadRequest().json(Res { message: format!("max {} topics allowed", settings.max_user_topics) }));
     }
-    
+
     for topic in &form.topics {
         if topic_re.captures(topic).is_none() {
-            return Ok(HttpResponse::BadRequest().json(Res { message: "Topics should be Alphanumeric with spaces allowed".to_string() }));
+            return Ok(HttpResponse::BadRequest().json(Res { message: TOPIC_VALIDATION_MESSAGE.to_string() }));
         }
     }
 
-    let _user = web::block(move || {
+    let user = web::block(move || {
         let mut conn = pool.get()?;
-        actions::insert_new_user(&mut conn, &form.user_id, &form.topics)
+        actions::insert_new_user(&mut conn, &form.user_id, form.email.as_deref(), &form.topics)
     })
     .await?
     .map_err(|e| {
         let detail = e.to_string();
         log::error!("Failed to add user: {:?}", e);
-        
+
         if let Some(diesel_error) = e.downcast_ref::<diesel::result::Error>() {
             match diesel_error {
                 diesel::result::Error::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _) => {
@@ -164,57 +190,115 @@ async fn add_user(pool: web::Data<DbPool>, form: web::Json<models::NewUser>) ->
         }
     })?;
 
-    Ok(HttpResponse::Created().json(Res { message: "User added successfully".to_string() }))
+    let jwt = auth::issue_token(&settings, &user.user_id)
+        .map_err(|e| error::ErrorInternalServerError(e.to_string()))?;
+
+    Ok(HttpResponse::Created().json(models::UserWithToken { user, jwt }))
+}
+
+#[post("/login")]
+async fn login(
+    pool: web::Data<DbPool>,
+    settings: web::Data<settings::Settings>,
+    form: web::Json<models::LoginRequest>,
+) -> actix_web::Result<impl Responder> {
+    let user = web::block(move || {
+        let mut conn = pool.get()?;
+        actions::get_user_by_id(&mut conn, &form.user_id)
+    })
+    .await?
+    .map_err(|e: Box<dyn std::error::Error + Send + Sync>| {
+        let detail = e.to_string();
+        log::error!("Failed to log in: {:?}", e);
+
+        if let Some(diesel::result::Error::NotFound) = e.downcast_ref::<diesel::result::Error>() {
+            error::InternalError::from_response(
+                e,
+                HttpResponse::NotFound().json(Res { message: "User not found".to_string() })
+            )
+        } else {
+            error::InternalError::from_response(
+                e,
+                HttpResponse::BadRequest().json(Res { message: detail })
+            )
+        }
+    })?;
+
+    let jwt = auth::issue_token(&settings, &user.user_id)
+        .map_err(|e| error::ErrorInternalServerError(e.to_string()))?;
+
+    Ok(HttpResponse::Ok().json(models::UserWithToken { user, jwt }))
 }
 
 #[post("/book")]
 async fn book_conference(
     pool: web::Data<DbPool>,
     queue_service: web::Data<queue::WaitlistQueueService>,
+    cache: web::Data<cache::LookupCache>,
+    auth: auth::AuthenticatedUser,
     form: web::Json<models::BookConferenceRequest>
-) -> actix_web::Result<impl Responder> {
+) -> Result<response::ApiResponse<models::BookConferenceResponse>, response::ApiError> {
+    let user_id = auth.0;
+
     let booking_result = web::block({
         let pool = pool.clone();
         let form = form.clone();
+        let cache = cache.clone();
+        let user_id = user_id.clone();
         move || {
             let mut conn = pool.get()?;
-            
-            // Check if conference exists
-            let conference = actions::get_conference_by_name(&mut conn, &form.name)?;
-            
+
+            // Check if conference exists - hot path, so serve it from the
+            // LRU cache when possible instead of hitting Postgres.
+            let conference = match cache.get_conference(&form.name) {
+                Some(conference) => conference,
+                None => {
+                    let conference = actions::get_conference_by_name(&mut conn, &form.name)?;
+                    cache.put_conference(conference.clone());
+                    conference
+                }
+            };
+
             // Check if user exists
-            let _user = actions::get_user_by_id(&mut conn, &form.user_id)?;
-            
+            let _user = actions::get_user_by_id(&mut conn, &user_id)?;
+
             // Check if conference has started
             let now = Utc::now().naive_utc();
             if conference.start_timestamp <= now {
                 return Err("Cannot book conference that has already started".into());
             }
-            
+
             // Check for overlapping bookings
-            if let Some(_) = actions::check_user_has_overlapping_booking(&mut conn, &form.user_id, conference.start_timestamp, conference.end_timestamp)? {
+            if let Some(_) = actions::check_user_has_overlapping_booking(&mut conn, &user_id, conference.start_timestamp, conference.end_timestamp)? {
                 return Err("User has an overlapping conference booking".into());
             }
-            
+
             // Use atomic booking to prevent race conditions (includes duplicate check)
-            let booking = actions::create_booking_atomic(&mut conn, conference.conference_id, &form.user_id)?;
-            
+            let booking = actions::create_booking_atomic(&mut conn, conference.conference_id, &user_id)?;
+
             // Store status for later use
             let booking_status = booking.status.clone();
             let booking_waitlist_position = booking.waitlist_position;
             let booking_id = booking.booking_id;
-            
-            // If booking was confirmed, remove from overlapping waitlists
+
+            // If booking was confirmed, a slot was consumed and overlapping
+            // waitlist entries may have been canceled - invalidate every
+            // cached entity that could now be stale.
             if booking_status == models::BookingStatus::CONFIRMED {
-                actions::remove_from_overlapping_waitlists(
+                cache.invalidate_conference(&form.name);
+
+                let canceled = actions::remove_from_overlapping_waitlists(
                     &mut conn,
-                    &form.user_id,
+                    &user_id,
                     conference.start_timestamp,
                     conference.end_timestamp,
                     conference.conference_id
                 )?;
+                for canceled_booking_id in canceled {
+                    cache.invalidate_booking(canceled_booking_id);
+                }
             }
-            
+
             Ok(models::BookConferenceResponse {
                 booking_id,
                 status: booking_status.clone(),
@@ -227,43 +311,145 @@ async fn book_conference(
             })
         }
     })
-    .await?
-    .map_err(|e: Box<dyn std::error::Error + Send + Sync>| {
-        let detail = e.to_string();
-        log::error!("Failed to book conference: {:?}", e);
-        error::InternalError::from_response(e, HttpResponse::BadRequest().json(Res { message: detail }))
-    })?;
+    .await
+    .map_err(response::ApiError::from)?
+    .map_err(response::ApiError::from)?;
 
-    // If booking was waitlisted, add to queue
+    // If booking was waitlisted, add to queue. This is awaited (rather than
+    // spawned) because a full waitlist queue should block the booking from
+    // succeeding instead of silently disappearing into a background task.
     if booking_result.status == models::BookingStatus::WAITLISTED {
-        // Use a separate task to avoid blocking the response
-        let queue_service_clone = queue_service.clone();
         let booking_id = booking_result.booking_id;
         let conference_name = form.name.clone();
-        
-        tokio::spawn(async move {
-            if let Err(e) = queue_service_clone.add_to_waitlist_by_booking_id(booking_id, &conference_name).await {
-                log::error!("Failed to add booking {} to waitlist queue: {:?}", booking_id, e);
-                // Don't fail the booking - the database transaction succeeded
-                // The waitlist functionality will still work through database queries
+
+        if let Err(e) = queue_service.add_to_waitlist_by_booking_id(booking_id, &conference_name).await {
+            if e.downcast_ref::<queue::QueueError>() == Some(&queue::QueueError::WaitlistFull) {
+                log::info!("Waitlist full for conference '{}', rejecting booking {}", conference_name, booking_id);
+
+                let pool = pool.clone();
+                let _ = web::block(move || {
+                    let mut conn = pool.get()?;
+                    actions::cancel_booking(&mut conn, booking_id)
+                })
+                .await;
+
+                return Err(response::ApiError::OverCapacity("Conference waitlist is full".to_string()));
             }
-        });
+
+            log::error!("Failed to add booking {} to waitlist queue: {:?}", booking_id, e);
+            // Don't fail the booking for other, likely-transient queue
+            // failures - the database transaction succeeded and the waitlist
+            // functionality will still work through database queries.
+        }
     }
 
-    Ok(HttpResponse::Created().json(booking_result))
+    let message = booking_result.message.clone();
+    Ok(response::ApiResponse::ok(message, booking_result))
+}
+
+// Per-slot availability for one resource under a conference - one entry per
+// `granularity_minutes` slot across the conference's window, for clients
+// that need to pick a specific timeslot rather than just joining the
+// conference's flat pool.
+#[get("/conference/{conference_name}/resource/{resource_name}/availability")]
+async fn get_resource_availability(
+    pool: web::Data<DbPool>,
+    path: web::Path<(String, String)>,
+) -> Result<response::ApiResponse<Vec<models::SlotAvailability>>, response::ApiError> {
+    let (conference_name, resource_name) = path.into_inner();
+
+    let result = web::block(move || {
+        let mut conn = pool.get()?;
+        let conference = actions::get_conference_by_name(&mut conn, &conference_name)?;
+        let resource = actions::get_resource_by_name(&mut conn, conference.conference_id, &resource_name)?;
+        actions::get_resource_slot_availability(&mut conn, &conference, &resource)
+    })
+    .await
+    .map_err(response::ApiError::from)?
+    .map_err(response::ApiError::from)?;
+
+    Ok(response::ApiResponse::ok("Resource availability retrieved", result))
+}
+
+// Books a specific `(resource, slot_start)` instead of joining a
+// conference's whole-conference pool via `/book`. Confirms immediately if
+// the slot has a free place, else waitlists it - see
+// `actions::create_resource_slot_booking_atomic` for how that's decided and
+// its auto-promotion caveat.
+#[post("/book/resource")]
+async fn book_resource_slot(
+    pool: web::Data<DbPool>,
+    auth: auth::AuthenticatedUser,
+    form: web::Json<models::BookResourceSlotRequest>,
+) -> Result<response::ApiResponse<models::BookConferenceResponse>, response::ApiError> {
+    let user_id = auth.0;
+
+    let booking_result = web::block({
+        let form = form.clone();
+        move || {
+            let mut conn = pool.get()?;
+
+            let conference = actions::get_conference_by_name(&mut conn, &form.name)?;
+            let resource = actions::get_resource_by_name(&mut conn, conference.conference_id, &form.resource_name)?;
+
+            if form.slot_start < conference.start_timestamp || form.slot_start >= conference.end_timestamp {
+                return Err("slot_start falls outside the conference's window".into());
+            }
+
+            let offset_minutes = (form.slot_start - conference.start_timestamp).num_minutes();
+            if offset_minutes % resource.granularity_minutes as i64 != 0 {
+                return Err("slot_start does not fall on a resource.granularity_minutes boundary".into());
+            }
+
+            let _user = actions::get_user_by_id(&mut conn, &user_id)?;
+
+            let booking = actions::create_resource_slot_booking_atomic(
+                &mut conn,
+                conference.conference_id,
+                &resource,
+                form.slot_start,
+                &user_id,
+            )?;
+
+            Ok(models::BookConferenceResponse {
+                booking_id: booking.booking_id,
+                status: booking.status.clone(),
+                message: match booking.status {
+                    models::BookingStatus::CONFIRMED => "Booking confirmed successfully".to_string(),
+                    models::BookingStatus::WAITLISTED => "Added to waitlist".to_string(),
+                    _ => "Booking created".to_string(),
+                },
+                waitlist_position: booking.waitlist_position,
+            })
+        }
+    })
+    .await
+    .map_err(response::ApiError::from)?
+    .map_err(response::ApiError::from)?;
+
+    let message = booking_result.message.clone();
+    Ok(response::ApiResponse::ok(message, booking_result))
 }
 
 #[get("/booking/{booking_id}")]
 async fn get_booking_status(
     pool: web::Data<DbPool>,
+    cache: web::Data<cache::LookupCache>,
     path: web::Path<i32>
-) -> actix_web::Result<impl Responder> {
+) -> Result<response::ApiResponse<models::BookingStatusResponse>, response::ApiError> {
     let booking_id = path.into_inner();
-    
+
     let result = web::block(move || {
         let mut conn = pool.get()?;
-        
-        let (booking, conference_name) = actions::get_booking_with_conference_name(&mut conn, booking_id)?;
+
+        let (booking, conference_name) = match cache.get_booking(booking_id) {
+            Some(entry) => entry,
+            None => {
+                let entry = actions::get_booking_with_conference_name(&mut conn, booking_id)?;
+                cache.put_booking(booking_id, entry.clone());
+                entry
+            }
+        };
         
         Ok(models::BookingStatusResponse {
             booking_id: booking.booking_id,
@@ -274,114 +460,321 @@ async fn get_booking_status(
             waitlist_position: booking.waitlist_position,
         })
     })
+    .await
+    .map_err(response::ApiError::from)?
+    .map_err(response::ApiError::from)?;
+
+    Ok(response::ApiResponse::ok("Booking status retrieved", result))
+}
+
+// Internal driver state for the `GET /booking/{booking_id}/events` stream
+// below: first yields the booking's current state so a freshly-opened
+// connection doesn't have to wait for the next change, then forwards every
+// subsequent broadcast plus a periodic keep-alive so idle connections aren't
+// dropped by intermediate proxies.
+enum BookingEventStreamState {
+    Initial {
+        receiver: broadcast::Receiver<models::BookingStatusResponse>,
+        initial: String,
+        keep_alive: tokio::time::Interval,
+    },
+    Streaming {
+        receiver: broadcast::Receiver<models::BookingStatusResponse>,
+        keep_alive: tokio::time::Interval,
+    },
+}
+
+fn sse_event(event: &models::BookingUpdateEvent) -> String {
+    format!("data: {}\n\n", serde_json::to_string(event).unwrap_or_default())
+}
+
+const BOOKING_EVENT_KEEP_ALIVE: std::time::Duration = std::time::Duration::from_secs(15);
+
+// Fetches `booking_id` (via `cache`, like the rest of the booking handlers)
+// and checks it belongs to `user_id`, for the two push-update endpoints
+// below. Returns the same `BookingStatusResponse` `get_booking_status`
+// would, so both endpoints can send it as the stream's first frame.
+async fn load_owned_booking_status(
+    pool: &web::Data<DbPool>,
+    cache: &web::Data<cache::LookupCache>,
+    booking_id: i32,
+    user_id: &str,
+) -> actix_web::Result<models::BookingStatusResponse> {
+    let pool = pool.clone();
+    let cache = cache.clone();
+    let user_id = user_id.to_string();
+
+    web::block(move || {
+        let mut conn = pool.get()?;
+        let (booking, conference_name) = match cache.get_booking(booking_id) {
+            Some(entry) => entry,
+            None => {
+                let entry = actions::get_booking_with_conference_name(&mut conn, booking_id)?;
+                cache.put_booking(booking_id, entry.clone());
+                entry
+            }
+        };
+
+        if booking.user_id.as_deref() != Some(user_id.as_str()) {
+            return Err("Booking does not belong to the authenticated user".into());
+        }
+
+        Ok(models::BookingStatusResponse {
+            booking_id: booking.booking_id,
+            status: booking.status,
+            conference_name,
+            can_confirm: booking.can_confirm.unwrap_or(false),
+            confirmation_deadline: booking.waitlist_confirmation_deadline,
+            waitlist_position: booking.waitlist_position,
+        })
+    })
     .await?
     .map_err(|e: Box<dyn std::error::Error + Send + Sync>| {
         let detail = e.to_string();
-        log::error!("Failed to get booking status: {:?}", e);
-        
-        if let Some(diesel_error) = e.downcast_ref::<diesel::result::Error>() {
-            match diesel_error {
-                diesel::result::Error::NotFound => {
-                    error::InternalError::from_response(
-                        e,
-                        HttpResponse::NotFound().json(Res { message: "Booking not found".to_string() })
-                    )
-                }
-                _ => error::InternalError::from_response(
-                    e,
-                    HttpResponse::BadRequest().json(Res { message: detail })
-                )
-            }
+        log::error!("Failed to get booking status for event stream: {:?}", e);
+
+        if let Some(diesel::result::Error::NotFound) = e.downcast_ref::<diesel::result::Error>() {
+            error::InternalError::from_response(
+                e,
+                HttpResponse::NotFound().json(Res { message: "Booking not found".to_string() })
+            ).into()
+        } else if detail.contains("does not belong") {
+            error::InternalError::from_response(
+                e,
+                HttpResponse::Forbidden().json(Res { message: detail })
+            ).into()
         } else {
             error::InternalError::from_response(
                 e,
                 HttpResponse::BadRequest().json(Res { message: detail })
-            )
+            ).into()
         }
-    })?;
+    })
+}
 
-    Ok(HttpResponse::Ok().json(result))
+#[get("/booking/{booking_id}/events")]
+async fn stream_booking_events(
+    pool: web::Data<DbPool>,
+    queue_service: web::Data<queue::WaitlistQueueService>,
+    cache: web::Data<cache::LookupCache>,
+    auth: auth::AuthenticatedUser,
+    path: web::Path<i32>,
+) -> actix_web::Result<impl Responder> {
+    let booking_id = path.into_inner();
+
+    // Confirm the booking exists and belongs to the caller before opening a
+    // long-lived stream for it, mirroring `get_booking_status`'s 404
+    // handling plus the ownership check `confirm_waitlist_booking` applies
+    // elsewhere.
+    let initial = load_owned_booking_status(&pool, &cache, booking_id, &auth.0).await?;
+
+    let receiver = queue_service.subscribe_booking_events(booking_id);
+    let state = BookingEventStreamState::Initial {
+        receiver,
+        initial: sse_event(&models::BookingUpdateEvent::new(initial)),
+        keep_alive: tokio::time::interval(BOOKING_EVENT_KEEP_ALIVE),
+    };
+
+    let stream = stream::unfold(state, |state| async move {
+        match state {
+            BookingEventStreamState::Initial { receiver, initial, keep_alive } => {
+                Some((
+                    Ok::<_, actix_web::Error>(web::Bytes::from(initial)),
+                    BookingEventStreamState::Streaming { receiver, keep_alive },
+                ))
+            }
+            BookingEventStreamState::Streaming { mut receiver, mut keep_alive } => loop {
+                tokio::select! {
+                    changed = receiver.recv() => {
+                        match changed {
+                            Ok(event) => {
+                                return Some((
+                                    Ok(web::Bytes::from(sse_event(&models::BookingUpdateEvent::new(event)))),
+                                    BookingEventStreamState::Streaming { receiver, keep_alive },
+                                ));
+                            }
+                            // A slow reader missed some events - the next recv()
+                            // picks up from where the broadcast channel still
+                            // has them, so just retry rather than ending the stream.
+                            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                            Err(broadcast::error::RecvError::Closed) => return None,
+                        }
+                    }
+                    _ = keep_alive.tick() => {
+                        return Some((
+                            Ok(web::Bytes::from_static(b": keep-alive\n\n")),
+                            BookingEventStreamState::Streaming { receiver, keep_alive },
+                        ));
+                    }
+                }
+            },
+        }
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .append_header(("Cache-Control", "no-cache"))
+        .streaming(stream))
+}
+
+// WebSocket counterpart to `stream_booking_events`, backed by
+// `WaitlistQueueService::subscribe_booking_updates`'s AMQP fan-out instead of
+// the in-process broadcast channel, so it keeps working if the HTTP worker
+// handling the subscription isn't the one whose consumer observed the
+// change. Emits the same `BookingUpdateEvent` frames as the SSE endpoint,
+// as text messages.
+#[get("/booking/{booking_id}/ws")]
+async fn stream_booking_updates_ws(
+    req: HttpRequest,
+    body: web::Payload,
+    pool: web::Data<DbPool>,
+    queue_service: web::Data<queue::WaitlistQueueService>,
+    cache: web::Data<cache::LookupCache>,
+    auth: auth::AuthenticatedUser,
+    path: web::Path<i32>,
+) -> actix_web::Result<HttpResponse> {
+    let booking_id = path.into_inner();
+    let initial = load_owned_booking_status(&pool, &cache, booking_id, &auth.0).await?;
+
+    let (response, mut session, mut msg_stream) = actix_ws::handle(&req, body)?;
+
+    let mut updates = queue_service
+        .subscribe_booking_updates(booking_id)
+        .await
+        .map_err(|e| error::ErrorInternalServerError(e.to_string()))?;
+
+    actix_web::rt::spawn(async move {
+        let initial_frame = serde_json::to_string(&models::BookingUpdateEvent::new(initial)).unwrap_or_default();
+        if session.text(initial_frame).await.is_err() {
+            return;
+        }
+
+        loop {
+            tokio::select! {
+                event = updates.recv() => {
+                    match event {
+                        Some(event) => {
+                            let frame = serde_json::to_string(&event).unwrap_or_default();
+                            if session.text(frame).await.is_err() {
+                                break;
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                msg = msg_stream.next() => {
+                    match msg {
+                        Some(Ok(actix_ws::Message::Ping(bytes))) => {
+                            if session.pong(&bytes).await.is_err() {
+                                break;
+                            }
+                        }
+                        Some(Ok(actix_ws::Message::Close(reason))) => {
+                            let _ = session.close(reason).await;
+                            break;
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(_)) | None => break,
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(response)
 }
 
 #[post("/confirm")]
 async fn confirm_waitlist_booking(
     pool: web::Data<DbPool>,
+    cache: web::Data<cache::LookupCache>,
+    auth: auth::AuthenticatedUser,
     form: web::Json<models::ConfirmBookingRequest>
-) -> actix_web::Result<impl Responder> {
+) -> Result<response::ApiResponse<()>, response::ApiError> {
     let booking_id = form.booking_id;
-    let user_id = form.user_id.clone();
-    
+    let user_id = auth.0;
+
     let _result = web::block({
         let pool = pool.clone();
         move || {
             let mut conn = pool.get()?;
-            
+
             // Get booking and conference info before confirmation
             let (_booking, conference_name) = actions::get_booking_with_conference_name(&mut conn, booking_id)?;
             let conference = actions::get_conference_by_name(&mut conn, &conference_name)?;
-            
+
             // Check if conference has started
             let now = Utc::now().naive_utc();
             if conference.start_timestamp <= now {
                 return Err("Cannot confirm booking for conference that has already started".into());
             }
-            
+
             // Use secure confirmation function that validates user ownership
             let confirmed_booking = actions::confirm_waitlist_booking_secure(&mut conn, booking_id, &user_id)?;
-            
+            cache.invalidate_booking(booking_id);
+            // Confirming consumed a slot, so the cached conference's
+            // `available_slots` is now stale too.
+            cache.invalidate_conference(&conference_name);
+
             // Remove from overlapping waitlists
-            actions::remove_from_overlapping_waitlists(
+            let canceled = actions::remove_from_overlapping_waitlists(
                 &mut conn,
                 &user_id,
                 conference.start_timestamp,
                 conference.end_timestamp,
                 conference.conference_id
             )?;
-            
+            for canceled_booking_id in canceled {
+                cache.invalidate_booking(canceled_booking_id);
+            }
+
             Ok((confirmed_booking, conference_name))
         }
     })
-    .await?
-    .map_err(|e: Box<dyn std::error::Error + Send + Sync>| {
-        let detail = e.to_string();
-        log::error!("Failed to confirm waitlist booking: {:?}", e);
-        error::InternalError::from_response(e, HttpResponse::BadRequest().json(Res { message: detail }))
-    })?;
+    .await
+    .map_err(response::ApiError::from)?
+    .map_err(response::ApiError::from)?;
 
-    Ok(HttpResponse::Ok().json(models::ApiResponse {
-        message: "Booking confirmed successfully".to_string(),
-    }))
+    Ok(response::ApiResponse::ok("Booking confirmed successfully", ()))
 }
 
 #[post("/cancel")]
 async fn cancel_booking(
     pool: web::Data<DbPool>,
     queue_service: web::Data<queue::WaitlistQueueService>,
+    cache: web::Data<cache::LookupCache>,
+    auth: auth::AuthenticatedUser,
     form: web::Json<models::BookingIdRequest>
-) -> actix_web::Result<impl Responder> {
+) -> Result<response::ApiResponse<()>, response::ApiError> {
     let booking_id = form.booking_id;
-    
+    let user_id = auth.0;
+
     let result = web::block({
         let pool = pool.clone();
+        let cache = cache.clone();
         move || {
             let mut conn = pool.get()?;
-            
+
             // Get booking info before cancellation
             let (booking, conference_name) = actions::get_booking_with_conference_name(&mut conn, booking_id)?;
             let was_confirmed = booking.status == models::BookingStatus::CONFIRMED;
-            
-            // Cancel the booking
-            let canceled_booking = actions::cancel_booking(&mut conn, booking_id)?;
-            
+
+            // Use secure cancellation function that validates user ownership
+            let canceled_booking = actions::cancel_booking_secure(&mut conn, booking_id, &user_id)?;
+            cache.invalidate_booking(booking_id);
+
+            // A confirmed cancellation frees a slot, so the cached conference's
+            // `available_slots` is now stale too.
+            if was_confirmed {
+                cache.invalidate_conference(&conference_name);
+            }
+
             Ok((canceled_booking, conference_name, was_confirmed))
         }
     })
-    .await?
-    .map_err(|e: Box<dyn std::error::Error + Send + Sync>| {
-        let detail = e.to_string();
-        log::error!("Failed to cancel booking: {:?}", e);
-        error::InternalError::from_response(e, HttpResponse::BadRequest().json(Res { message: detail }))
-    })?;
+    .await
+    .map_err(response::ApiError::from)?
+    .map_err(response::ApiError::from)?;
 
     // If a confirmed booking was canceled, notify waitlist
     if result.2 {
@@ -394,9 +787,7 @@ async fn cancel_booking(
         });
     }
 
-    Ok(HttpResponse::Ok().json(models::ApiResponse {
-        message: "Booking canceled successfully".to_string(),
-    }))
+    Ok(response::ApiResponse::ok("Booking canceled successfully", ()))
 }
 
 #[get("/conference/{conference_name}/bookings")]
@@ -467,21 +858,138 @@ async fn get_conference_bookings(
     Ok(HttpResponse::Ok().json(result))
 }
 
+#[get("/user/{user_id}/recommendations")]
+async fn get_user_recommendations(
+    pool: web::Data<DbPool>,
+    auth: auth::AuthenticatedUser,
+    path: web::Path<String>,
+) -> actix_web::Result<impl Responder> {
+    let user_id = path.into_inner();
+
+    if auth.0 != user_id {
+        return Ok(HttpResponse::Forbidden().json(Res { message: "Cannot view another user's recommendations".to_string() }));
+    }
+
+    let result = web::block(move || {
+        let mut conn = pool.get()?;
+
+        // Verify the user exists before ranking conferences for them, mirroring
+        // the rest of the handlers' 404-on-missing-entity convention.
+        actions::get_user_by_id(&mut conn, &user_id)?;
+
+        actions::get_recommended_conferences(&mut conn, &user_id)
+    })
+    .await?
+    .map_err(|e: Box<dyn std::error::Error + Send + Sync>| {
+        let detail = e.to_string();
+        log::error!("Failed to get recommendations: {:?}", e);
+
+        if let Some(diesel_error) = e.downcast_ref::<diesel::result::Error>() {
+            match diesel_error {
+                diesel::result::Error::NotFound => {
+                    error::InternalError::from_response(
+                        e,
+                        HttpResponse::NotFound().json(Res { message: "User not found".to_string() })
+                    )
+                }
+                _ => error::InternalError::from_response(
+                    e,
+                    HttpResponse::BadRequest().json(Res { message: detail })
+                )
+            }
+        } else {
+            error::InternalError::from_response(
+                e,
+                HttpResponse::BadRequest().json(Res { message: detail })
+            )
+        }
+    })?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+#[get("/user/{user_id}/calendar.ics")]
+async fn get_user_calendar(
+    pool: web::Data<DbPool>,
+    auth: auth::AuthenticatedUser,
+    path: web::Path<String>,
+) -> actix_web::Result<impl Responder> {
+    let user_id = path.into_inner();
+
+    if auth.0 != user_id {
+        return Ok(HttpResponse::Forbidden().json(Res { message: "Cannot view another user's calendar".to_string() }));
+    }
+
+    let result = web::block(move || {
+        let mut conn = pool.get()?;
+
+        // Verify the user exists before building a feed for them, mirroring
+        // the rest of the handlers' 404-on-missing-entity convention.
+        actions::get_user_by_id(&mut conn, &user_id)?;
+
+        actions::get_user_calendar_bookings(&mut conn, &user_id)
+    })
+    .await?
+    .map_err(|e: Box<dyn std::error::Error + Send + Sync>| {
+        let detail = e.to_string();
+        log::error!("Failed to build calendar feed: {:?}", e);
+
+        if let Some(diesel_error) = e.downcast_ref::<diesel::result::Error>() {
+            match diesel_error {
+                diesel::result::Error::NotFound => {
+                    error::InternalError::from_response(
+                        e,
+                        HttpResponse::NotFound().json(Res { message: "User not found".to_string() })
+                    )
+                }
+                _ => error::InternalError::from_response(
+                    e,
+                    HttpResponse::BadRequest().json(Res { message: detail })
+                )
+            }
+        } else {
+            error::InternalError::from_response(
+                e,
+                HttpResponse::BadRequest().json(Res { message: detail })
+            )
+        }
+    })?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/calendar")
+        .body(icalendar::render_user_calendar(&result)))
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     dotenvy::dotenv().ok();
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
 
+    let settings = settings::Settings::load().unwrap_or_else(|e| {
+        log::warn!("Failed to load config.toml/env settings, using defaults: {:?}", e);
+        settings::Settings::default()
+    });
+
     // initialize DB pool outside of `HttpServer::new` so that it is shared across all workers
-    let pool = initialize_db_pool();
-    
+    let pool = initialize_db_pool(&settings);
+
+    // Shared conference/booking lookup cache. Created once here (like
+    // `pool`) so the queue consumers and every HTTP worker invalidate and
+    // read the same cached entries.
+    let cache = cache::LookupCache::new(settings.cache_capacity);
+
     // Initialize the waitlist queue service
-    let mut queue_service = queue::WaitlistQueueService::new(pool.clone());
+    let http_host = settings.http_host.clone();
+    let http_port = settings.http_port;
+    let mut queue_service = queue::WaitlistQueueService::new(pool.clone(), settings.clone(), cache.clone());
     queue_service.initialize().await.unwrap();
     
     // Add a small delay to ensure RabbitMQ setup is complete
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    
+
+    // Expose queue/consumer metrics for Prometheus to scrape
+    queue_service.metrics().serve(([127, 0, 0, 1], 9090).into());
+
     // Start background queue consumers
     let queue_service_clone1 = queue_service.clone();
     tokio::spawn(async move {
@@ -497,17 +1005,39 @@ async fn main() -> std::io::Result<()> {
             log::error!("Failed to start conference event consumers: {:?}", e);
         }
     });
-    
+
+    // Make waitlist promotion event-driven off the DB trigger instead of
+    // relying on every slot-freeing code path to call publish_slot_available.
+    if let Err(e) = queue_service.start_listening_slot_changes().await {
+        log::error!("Failed to start slot_available listener: {:?}", e);
+    }
+
+    // Send confirmation-deadline reminders for bookings the promotion path
+    // above already notified once - skipped entirely if no SMTP notifier
+    // could be built (e.g. `smtp_host` unset).
+    if let Some(notifier) = queue_service.notifier() {
+        let reminder_pool = pool.clone();
+        let lead_secs = settings.confirmation_reminder_lead_secs;
+        let poll_interval = tokio::time::Duration::from_secs(settings.confirmation_reminder_poll_interval_secs);
+        tokio::spawn(async move {
+            notifier::run_confirmation_reminder_scheduler(reminder_pool, notifier, lead_secs, poll_interval).await;
+        });
+    }
+
     // Create a shared reference to the queue service that can be used by request handlers
     let queue_service = web::Data::new(queue_service);
+    let settings = web::Data::new(settings);
+    let cache = web::Data::new(cache);
 
-    log::info!("starting HTTP server at http://localhost:8080");
+    log::info!("starting HTTP server at http://{}:{}", http_host, http_port);
 
     let http = HttpServer::new(move || {
         App::new()
             // add DB pool handle to app data; enables use of `web::Data<DbPool>` extractor
             .app_data(web::Data::new(pool.clone()))
             .app_data(queue_service.clone())
+            .app_data(settings.clone())
+            .app_data(cache.clone())
             .wrap(middleware::Logger::default())
             .app_data(web::JsonConfig::default().error_handler(|err, _req| {
                 let detail = err.to_string();
@@ -524,23 +1054,31 @@ async fn main() -> std::io::Result<()> {
                 error::InternalError::from_response(err, response).into()
             }))
             .service(add_user)
+            .service(login)
             .service(add_conference)
             .service(book_conference)
+            .service(get_resource_availability)
+            .service(book_resource_slot)
             .service(get_booking_status)
+            .service(stream_booking_events)
+            .service(stream_booking_updates_ws)
             .service(get_conference_bookings)
+            .service(get_user_recommendations)
+            .service(get_user_calendar)
             .service(confirm_waitlist_booking)
             .service(cancel_booking)
     })
-    .bind(("127.0.0.1", 8080)).unwrap()
+    .bind((http_host, http_port)).unwrap()
     .run();
 
     http.await
 }
 
-fn initialize_db_pool() -> DbPool {
+fn initialize_db_pool(settings: &settings::Settings) -> DbPool {
     let conn_spec = std::env::var("DATABASE_URL").expect("DATABASE_URL should be set");
     let manager = r2d2::ConnectionManager::<PgConnection>::new(conn_spec);
     r2d2::Pool::builder()
+        .max_size(settings.db_pool_max_size)
         .build(manager)
         .expect("database URL should be valid path to SQLite DB file")
 }
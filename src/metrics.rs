@@ -0,0 +1,122 @@
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use log::{error, info};
+use prometheus::{Encoder, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts, Registry, TextEncoder};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+// Prometheus metrics for `WaitlistQueueService`, modeled on the approach
+// nostr-rs-relay uses in its server: a single `Registry` owns every series,
+// and a small hyper endpoint exposes it for scraping rather than pulling in
+// a full metrics framework.
+#[derive(Clone)]
+pub struct QueueMetrics {
+    registry: Registry,
+    // Outcome of each queue operation, labeled by operation name (e.g.
+    // "add_to_waitlist") and outcome ("ok"/"err").
+    pub queue_operations: IntCounterVec,
+    pub waitlist_promotions: IntCounter,
+    pub expired_confirmations_processed: IntCounter,
+    pub channel_pool_occupancy: IntGauge,
+    pub queue_operation_duration: Histogram,
+    // Messages routed to a `<queue>.parking` queue after exhausting
+    // `max_redeliveries`, so operators can spot stuck bookings that need
+    // manual intervention rather than digging through broker queue depths.
+    pub parked_messages: IntCounter,
+}
+
+impl QueueMetrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let queue_operations = IntCounterVec::new(
+            Opts::new("queue_operations_total", "Outcome of each queue operation, labeled by operation and outcome"),
+            &["operation", "outcome"],
+        ).expect("metric name and labels are static and valid");
+
+        let waitlist_promotions = IntCounter::new(
+            "waitlist_promotions_total",
+            "Bookings promoted from WAITLISTED to ConfirmationPending",
+        ).expect("metric name is static and valid");
+
+        let expired_confirmations_processed = IntCounter::new(
+            "expired_confirmations_processed_total",
+            "Expired-confirmation dead letters processed by the consumer",
+        ).expect("metric name is static and valid");
+
+        let channel_pool_occupancy = IntGauge::new(
+            "channel_pool_occupancy",
+            "Channels currently sitting in WaitlistQueueService's pooled-channel Vec",
+        ).expect("metric name is static and valid");
+
+        let queue_operation_duration = Histogram::with_opts(HistogramOpts::new(
+            "queue_operation_duration_seconds",
+            "Time spent inside a safe_queue_operation closure, start to completion",
+        )).expect("metric name is static and valid");
+
+        let parked_messages = IntCounter::new(
+            "parked_messages_total",
+            "Messages routed to a <queue>.parking queue after exhausting max_redeliveries",
+        ).expect("metric name is static and valid");
+
+        registry.register(Box::new(queue_operations.clone())).expect("metric registered exactly once");
+        registry.register(Box::new(waitlist_promotions.clone())).expect("metric registered exactly once");
+        registry.register(Box::new(expired_confirmations_processed.clone())).expect("metric registered exactly once");
+        registry.register(Box::new(channel_pool_occupancy.clone())).expect("metric registered exactly once");
+        registry.register(Box::new(queue_operation_duration.clone())).expect("metric registered exactly once");
+        registry.register(Box::new(parked_messages.clone())).expect("metric registered exactly once");
+
+        Self {
+            registry,
+            queue_operations,
+            waitlist_promotions,
+            expired_confirmations_processed,
+            channel_pool_occupancy,
+            queue_operation_duration,
+            parked_messages,
+        }
+    }
+
+    fn gather(&self) -> Vec<u8> {
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&metric_families, &mut buffer)
+            .expect("encoding gathered metrics to a Vec<u8> cannot fail");
+        buffer
+    }
+
+    // Serves `/metrics` on `addr` for Prometheus to scrape. Spawned once from
+    // `main` alongside the queue consumers.
+    pub fn serve(self, addr: SocketAddr) {
+        tokio::spawn(async move {
+            let make_svc = make_service_fn(move |_conn| {
+                let metrics = self.clone();
+                async move {
+                    Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                        let metrics = metrics.clone();
+                        async move {
+                            let response = if req.uri().path() == "/metrics" {
+                                Response::new(Body::from(metrics.gather()))
+                            } else {
+                                Response::builder().status(404).body(Body::empty()).unwrap()
+                            };
+                            Ok::<_, Infallible>(response)
+                        }
+                    }))
+                }
+            });
+
+            info!("Serving Prometheus metrics at http://{}/metrics", addr);
+            if let Err(e) = Server::bind(&addr).serve(make_svc).await {
+                error!("Metrics server error: {:?}", e);
+            }
+        });
+    }
+}
+
+impl Default for QueueMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
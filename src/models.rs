@@ -1,20 +1,43 @@
 use serde::{Deserialize, Serialize};
-use crate::schema::{bookings, users, conferences};
+use crate::schema::{bookings, users, conferences, resources};
 use chrono::NaiveDateTime;
 use diesel::{deserialize::{self, FromSql}, pg::{Pg, PgValue}, serialize::{self, Output, ToSql}, sql_types::Text, Insertable, Selectable};
+use std::fmt;
 
 #[derive(Debug, Clone, Queryable, Insertable, Serialize, Deserialize, Selectable)]
 #[diesel(table_name = users)]
 pub struct User {
     pub user_id: String,
+    // Address `notifier::SmtpNotifier` sends booking notifications to.
+    // `None` means the user hasn't supplied one, so notifications for them
+    // are skipped rather than attempted against the (non-email) user_id.
+    pub email: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewUser {
     pub user_id: String,
+    #[serde(default)]
+    pub email: Option<String>,
     pub topics: Vec<String>,
 }
 
+// Response for `add_user`/`login` - the created/existing `User` flattened
+// alongside the JWT `auth::issue_token` signed for it, so the caller gets
+// both the user record and a token it can use as a `Bearer` credential in
+// one response.
+#[derive(Debug, Serialize)]
+pub struct UserWithToken {
+    #[serde(flatten)]
+    pub user: User,
+    pub jwt: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    pub user_id: String,
+}
+
 #[derive(Debug, Clone, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = conferences)]
 pub struct Conference {
@@ -46,7 +69,63 @@ pub struct NewConference {
     pub start: String,
     pub end: String,
     pub slots: i32,
-    pub topics: Vec<String>
+    pub topics: Vec<String>,
+    // Optional iCalendar RRULE (e.g. "FREQ=WEEKLY;COUNT=10"); when present,
+    // `actions::create_recurring_conferences` expands `start`/`end` as the
+    // DTSTART/duration of the first occurrence into one conference row per
+    // occurrence instead of just one.
+    #[serde(default)]
+    pub rrule: Option<String>,
+    // Bookable resources to create under the conference (e.g. a room
+    // divided into timeslots), handled separately from `slots`'s
+    // whole-conference pool. Empty by default - a conference with no
+    // resources behaves exactly as before.
+    #[serde(default)]
+    pub resources: Vec<NewResource>,
+}
+
+#[derive(Debug, Clone, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = resources)]
+pub struct Resource {
+    pub resource_id: i32,
+    pub conference_id: i32,
+    pub name: String,
+    pub granularity_minutes: i32,
+    pub capacity: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = resources)]
+pub struct NewResourceInternal {
+    pub conference_id: i32,
+    pub name: String,
+    pub granularity_minutes: i32,
+    pub capacity: i32,
+}
+
+// One resource definition supplied on `NewConference::resources`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewResource {
+    pub name: String,
+    pub granularity_minutes: i32,
+    pub capacity: i32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BookResourceSlotRequest {
+    pub name: String,
+    pub resource_name: String,
+    pub slot_start: NaiveDateTime,
+}
+
+// One entry of `GET /conference/{name}/resource/{resource_name}/availability` -
+// how many of `places_bookable` (the resource's capacity) remain open at `hour`.
+#[derive(Debug, Serialize)]
+pub struct SlotAvailability {
+    pub hour: NaiveDateTime,
+    pub places_available: i32,
+    pub places_bookable: i32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AsExpression, FromSqlRow)]
@@ -70,14 +149,41 @@ impl ToSql<crate::schema::sql_types::BookingStatus, Pg> for BookingStatus {
     }
 }
 
+// Dedicated error for `BookingStatus::from_sql` so a row holding a status
+// string the binary doesn't recognize (e.g. a value a newer migration added)
+// is distinguishable from any other `DeserializationError` diesel might
+// raise for this column - callers can `downcast_ref::<BookingStatusError>()`
+// the way `response::ApiError::from` already downcasts `diesel::result::Error`,
+// and choose to treat `UnrecognizedStatus` as a soft skip instead of a hard
+// failure, mirroring how `BookingUpdateFanoutConsumer` drops a malformed
+// frame instead of tearing down the whole subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookingStatusError {
+    UnrecognizedStatus(String),
+    InvalidUtf8,
+}
+
+impl fmt::Display for BookingStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookingStatusError::UnrecognizedStatus(s) => write!(f, "Unrecognized booking status: {}", s),
+            BookingStatusError::InvalidUtf8 => write!(f, "booking status column was not valid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for BookingStatusError {}
+
 impl FromSql<crate::schema::sql_types::BookingStatus, Pg> for BookingStatus {
     fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
-        match <String as FromSql<Text, Pg>>::from_sql(bytes)?.as_str() {
+        let raw = <String as FromSql<Text, Pg>>::from_sql(bytes).map_err(|_| BookingStatusError::InvalidUtf8)?;
+
+        match raw.as_str() {
             "CONFIRMED" => Ok(BookingStatus::CONFIRMED),
             "WAITLISTED" => Ok(BookingStatus::WAITLISTED),
             "CANCELED" => Ok(BookingStatus::CANCELED),
             "CONFIRMATION_PENDING" => Ok(BookingStatus::ConfirmationPending),
-            s => Err(format!("Unrecognized booking status: {}", s).into()),
+            s => Err(Box::new(BookingStatusError::UnrecognizedStatus(s.to_string()))),
         }
     }
 }
@@ -94,6 +200,15 @@ pub struct Booking {
     pub canceled_at: Option<NaiveDateTime>,
     pub can_confirm: Option<bool>,
     pub waitlist_position: Option<i32>,
+    // Set by `actions::mark_reminder_sent` once `notifier::run_confirmation_reminder_scheduler`
+    // has sent this booking's confirmation-deadline reminder. `None` means none has gone out yet.
+    pub reminder_sent_at: Option<NaiveDateTime>,
+    // The specific `Resource`/slot this booking occupies, set by
+    // `actions::create_resource_slot_booking_atomic`. Both stay `None` for
+    // the whole-conference booking path, which tracks capacity through
+    // `conferences.available_slots` instead.
+    pub resource_id: Option<i32>,
+    pub slot_start: Option<NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -104,13 +219,14 @@ pub struct NewBooking {
     pub status: BookingStatus,
     pub waitlist_position: Option<i32>,
     pub can_confirm: Option<bool>,
+    pub resource_id: Option<i32>,
+    pub slot_start: Option<NaiveDateTime>,
 }
 
 // Request/Response models for API
 #[derive(Debug, Deserialize, Clone)]
 pub struct BookConferenceRequest {
     pub name: String,
-    pub user_id: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -126,14 +242,12 @@ pub struct BookingIdRequest {
     pub booking_id: i32,
 }
 
-// 🔒 SECURITY FIX: New secure confirmation request that includes user authorization
 #[derive(Debug, Deserialize)]
 pub struct ConfirmBookingRequest {
     pub booking_id: i32,
-    pub user_id: String,  // Required for security - only the booking owner can confirm
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BookingStatusResponse {
     pub booking_id: i32,
     pub status: BookingStatus,
@@ -143,7 +257,38 @@ pub struct BookingStatusResponse {
     pub waitlist_position: Option<i32>,
 }
 
+// Wire envelope for the booking-update push stream (`GET /booking/{id}/events`
+// and `GET /booking/{id}/ws`) - `event` is always `"booking_update"`, kept as
+// a field rather than a bare payload so the frame format can grow new event
+// types later without breaking existing subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookingUpdateEvent {
+    pub event: String,
+    pub payload: BookingStatusResponse,
+}
+
+impl BookingUpdateEvent {
+    pub fn new(payload: BookingStatusResponse) -> Self {
+        Self { event: "booking_update".to_string(), payload }
+    }
+}
+
 #[derive(Debug, Serialize)]
-pub struct ApiResponse {
-    pub message: String,
+pub struct CreateConferencesResponse {
+    pub conference_ids: Vec<i32>,
+}
+
+// A recommended conference from `GET /user/{user_id}/recommendations`,
+// ranked by `match_count` - how many of the user's `user_interests` topics
+// overlap with this conference's `conference_topics`.
+#[derive(Debug, Serialize)]
+pub struct ConferenceRecommendation {
+    pub conference_id: i32,
+    pub name: String,
+    pub location: String,
+    pub start_timestamp: NaiveDateTime,
+    pub end_timestamp: NaiveDateTime,
+    pub available_slots: i32,
+    pub matched_topics: Vec<String>,
+    pub match_count: i32,
 }
\ No newline at end of file
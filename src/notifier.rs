@@ -0,0 +1,208 @@
+// Delivery channel for the "your slot is available" message and the
+// confirmation-deadline reminder, sent when a booking becomes confirmable
+// (`queue::WaitlistQueueService::promote_next_waitlisted_person`) and while
+// its deadline approaches (`run_confirmation_reminder_scheduler` below).
+// Modeled on `queue::MqttHandle` - an optional, best-effort bridge that
+// never blocks or rolls back the booking-state change that triggered it.
+// `SmtpNotifier` is the only implementation; a webhook-backed one
+// (`Settings::webhook_url`) can be added later by implementing this trait.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::PgConnection;
+use lettre::{
+    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+use log::warn;
+
+use crate::actions;
+use crate::settings::Settings;
+
+type DbPool = Pool<ConnectionManager<PgConnection>>;
+
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify_slot_available(
+        &self,
+        user_id: &str,
+        booking_id: i32,
+        conference_name: &str,
+        confirmation_deadline: DateTime<Utc>,
+    );
+
+    async fn notify_confirmation_reminder(
+        &self,
+        user_id: &str,
+        booking_id: i32,
+        conference_name: &str,
+        confirmation_deadline: DateTime<Utc>,
+    );
+}
+
+pub struct SmtpNotifier {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    // Looked up per-send to resolve `user_id` (an alphanumeric username, not
+    // an address - see `models::User::email`) to the address to notify.
+    db_pool: DbPool,
+}
+
+impl SmtpNotifier {
+    // Builds a notifier from `settings.smtp_*`. Only call this when
+    // `settings.smtp_host` is non-empty - an empty host means email delivery
+    // is disabled, the same convention `WaitlistQueueService::mqtt_broker`
+    // being `None` uses for the MQTT bridge.
+    pub fn new(settings: &Settings, db_pool: DbPool) -> Result<Self, lettre::transport::smtp::Error> {
+        let mut builder =
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&settings.smtp_host)?.port(settings.smtp_port);
+
+        if !settings.smtp_username.is_empty() {
+            builder = builder.credentials(Credentials::new(
+                settings.smtp_username.clone(),
+                settings.smtp_password.clone(),
+            ));
+        }
+
+        let from = settings
+            .smtp_from
+            .parse()
+            .unwrap_or_else(|_| "waitlist@localhost".parse().expect("static address is valid"));
+
+        Ok(Self { mailer: builder.build(), from, db_pool })
+    }
+
+    async fn send(&self, user_id: &str, subject: String, body: String) {
+        let pool = self.db_pool.clone();
+        let uid = user_id.to_string();
+        let email = match tokio::task::spawn_blocking(move || {
+            let mut conn = pool.get()?;
+            actions::get_user_email(&mut conn, &uid)
+        })
+        .await
+        {
+            Ok(Ok(email)) => email,
+            Ok(Err(e)) => {
+                warn!("Failed to look up email for user '{}': {:?}", user_id, e);
+                return;
+            }
+            Err(e) => {
+                warn!("Email lookup task for user '{}' panicked: {:?}", user_id, e);
+                return;
+            }
+        };
+
+        let Some(email) = email else {
+            warn!("User '{}' has no email on file - skipping booking notification", user_id);
+            return;
+        };
+
+        let to: Mailbox = match email.parse() {
+            Ok(to) => to,
+            Err(e) => {
+                warn!("Email on file for user '{}' ('{}') is not a valid address: {:?}", user_id, email, e);
+                return;
+            }
+        };
+
+        let message = match Message::builder().from(self.from.clone()).to(to).subject(subject).body(body) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!("Failed to build booking notification email for '{}': {:?}", user_id, e);
+                return;
+            }
+        };
+
+        if let Err(e) = self.mailer.send(message).await {
+            warn!("Failed to send booking notification email to '{}': {:?}", user_id, e);
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for SmtpNotifier {
+    async fn notify_slot_available(
+        &self,
+        user_id: &str,
+        booking_id: i32,
+        conference_name: &str,
+        confirmation_deadline: DateTime<Utc>,
+    ) {
+        self.send(
+            user_id,
+            format!("Your slot for {} is available", conference_name),
+            format!(
+                "Booking #{} for '{}' is ready to confirm. Confirm by {} or it will be released back to the waitlist.",
+                booking_id, conference_name, confirmation_deadline.to_rfc3339(),
+            ),
+        )
+        .await;
+    }
+
+    async fn notify_confirmation_reminder(
+        &self,
+        user_id: &str,
+        booking_id: i32,
+        conference_name: &str,
+        confirmation_deadline: DateTime<Utc>,
+    ) {
+        self.send(
+            user_id,
+            format!("Reminder: confirm your slot for {}", conference_name),
+            format!(
+                "Booking #{} for '{}' still needs confirming before {} or it will be released back to the waitlist.",
+                booking_id, conference_name, confirmation_deadline.to_rfc3339(),
+            ),
+        )
+        .await;
+    }
+}
+
+// Polls every `poll_interval` for `ConfirmationPending` bookings entering
+// `lead_secs` of their deadline and sends each one a reminder through
+// `notifier`, deduplicated via `bookings.reminder_sent_at` so a booking
+// already reminded isn't re-notified on a later poll. Call once from
+// `main`, spawned onto its own task - it runs until the process exits.
+pub async fn run_confirmation_reminder_scheduler(
+    pool: DbPool,
+    notifier: Arc<dyn Notifier>,
+    lead_secs: i64,
+    poll_interval: std::time::Duration,
+) {
+    let mut ticker = tokio::time::interval(poll_interval);
+
+    loop {
+        ticker.tick().await;
+
+        let mut conn = match pool.get() {
+            Ok(conn) => conn,
+            Err(e) => {
+                warn!("Reminder scheduler failed to get a DB connection: {:?}", e);
+                continue;
+            }
+        };
+
+        let due = match actions::get_bookings_needing_confirmation_reminder(&mut conn, lead_secs) {
+            Ok(due) => due,
+            Err(e) => {
+                warn!("Reminder scheduler failed to query bookings needing a reminder: {:?}", e);
+                continue;
+            }
+        };
+
+        for (booking, conference_name) in due {
+            let (Some(deadline), Some(user_id)) = (booking.waitlist_confirmation_deadline, booking.user_id.clone()) else {
+                continue;
+            };
+            let deadline = DateTime::<Utc>::from_naive_utc_and_offset(deadline, Utc);
+
+            notifier.notify_confirmation_reminder(&user_id, booking.booking_id, &conference_name, deadline).await;
+
+            if let Err(e) = actions::mark_reminder_sent(&mut conn, booking.booking_id) {
+                warn!("Failed to mark reminder sent for booking {}: {:?}", booking.booking_id, e);
+            }
+        }
+    }
+}
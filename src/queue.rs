@@ -1,28 +1,189 @@
 use amqprs::{
-    callbacks::{DefaultChannelCallback, DefaultConnectionCallback}, 
-    channel::{BasicConsumeArguments, BasicPublishArguments, Channel, QueueBindArguments, QueueDeclareArguments, BasicAckArguments, BasicNackArguments}, 
-    connection::{Connection, OpenConnectionArguments}, 
-    consumer::AsyncConsumer, 
-    BasicProperties, 
+    callbacks::{ChannelCallback, DefaultChannelCallback, DefaultConnectionCallback},
+    channel::{BasicConsumeArguments, BasicPublishArguments, BasicQosArguments, Channel, ConfirmSelectArguments, QueueBindArguments, QueueDeclareArguments, BasicAckArguments, BasicNackArguments},
+    connection::{Connection, OpenConnectionArguments},
+    consumer::AsyncConsumer,
+    BasicProperties,
     FieldTable,
     Deliver,
 };
+use async_trait::async_trait;
 use diesel::{
     prelude::*,
     r2d2::{ConnectionManager, Pool},
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use chrono::{DateTime, Utc, Duration};
 use log::{error, info, warn};
 use uuid::Uuid;
-use crate::models::{Booking, Conference, BookingStatus};
+use governor::{state::keyed::DashMapStateStore, clock::DefaultClock, Quota, RateLimiter};
+use crate::metrics::QueueMetrics;
+use crate::models::{Booking, BookingUpdateEvent, Conference, BookingStatus, BookingStatusResponse};
 use crate::schema::{bookings, conferences};
-use tokio::sync::Mutex;
+use crate::settings::{DelayStrategy, Settings};
+use tokio::sync::{broadcast, mpsc, oneshot, Mutex};
+use futures_util::StreamExt;
+use tokio_postgres::{AsyncMessage, NoTls};
+use dashmap::DashMap;
 
 type DbPool = Pool<ConnectionManager<PgConnection>>;
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
+// Per-conference GCRA rate limiter for publish-side queue operations, keyed
+// by conference name (a `DashMap` under the hood) so one conference's churn
+// can't starve another's quota.
+type ConferenceRateLimiter = RateLimiter<String, DashMapStateStore<String>, DefaultClock>;
+
+// How long to wait for the broker to ack/nack a confirm-mode publish before
+// treating it as failed and running the caller's compensating DB update.
+const PUBLISH_CONFIRM_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+// Default per-conference waitlist queue cap, used when `WaitlistQueueService`
+// is constructed via `new` rather than a capacity-aware constructor.
+const DEFAULT_WAITLIST_CAPACITY: i64 = 500;
+
+// Default retry budget for the dead-letter-driven consumers before a poison
+// message is routed to its parking queue instead of retried again.
+const DEFAULT_MAX_REDELIVERIES: i64 = 5;
+
+// How long a message waits in a retry queue before being dead-lettered back
+// to its original queue for another attempt.
+const RETRY_QUEUE_TTL_MS: i64 = 5000;
+
+// Default per-conference publish quota: sustained rate plus a small burst
+// allowance, used unless overridden via `with_publish_quota`.
+const DEFAULT_PUBLISH_RATE_PER_SECOND: u32 = 5;
+const DEFAULT_PUBLISH_BURST: u32 = 2;
+
+// Postgres NOTIFY channel a `conferences_notify_slot_available` trigger
+// (see migrations/2026-07-26-000000_notify_slot_available) fires on whenever
+// `available_slots` goes from 0 to positive. `start_listening_slot_changes`
+// holds a dedicated `LISTEN` connection on this channel.
+const SLOT_AVAILABLE_CHANNEL: &str = "slot_available";
+
+// Buffer size of each per-booking SSE broadcast channel created by
+// `WaitlistQueueService::subscribe_booking_events`. Small on purpose - a slow
+// SSE client only needs to catch up on the latest status, not replay a long
+// history of intermediate ones.
+const BOOKING_EVENT_CHANNEL_CAPACITY: usize = 16;
+
+// Distinguishes why a confirm-mode publish didn't succeed, so callers of
+// `publish_confirmed` can react differently to a broker-side rejection (e.g.
+// an `x-overflow: reject-publish` queue at capacity) versus a publish that
+// simply never got confirmed in time.
+#[derive(Debug, PartialEq, Eq)]
+enum PublishConfirmError {
+    Nacked,
+    TimedOut,
+}
+
+impl std::fmt::Display for PublishConfirmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PublishConfirmError::Nacked => write!(f, "broker rejected publish (nack)"),
+            PublishConfirmError::TimedOut => write!(f, "timed out waiting for broker publish confirm"),
+        }
+    }
+}
+
+impl std::error::Error for PublishConfirmError {}
+
+// Typed errors surfaced by queue operations so callers (e.g. the booking
+// handlers) can distinguish "this is full" from an opaque broker failure.
+#[derive(Debug, PartialEq, Eq)]
+pub enum QueueError {
+    WaitlistFull,
+    RateLimited,
+}
+
+impl std::fmt::Display for QueueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueueError::WaitlistFull => write!(f, "conference waitlist is full"),
+            QueueError::RateLimited => write!(f, "conference publish rate limit exceeded"),
+        }
+    }
+}
+
+impl std::error::Error for QueueError {}
+
+// Outcome of a single publisher-confirmed publish, delivered via the oneshot
+// registered in `PendingConfirms` when the broker's ack/nack arrives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfirmOutcome {
+    Ack,
+    Nack,
+}
+
+// Tracks publish-sequence-number -> waiting confirmation for a single channel
+// opened in confirm mode. amqprs numbers confirms starting at 1 and increments
+// once per publish on the channel, matching `delivery_tag` on the ack/nack.
+#[derive(Default)]
+struct PendingConfirms {
+    waiters: HashMap<u64, oneshot::Sender<ConfirmOutcome>>,
+}
+
+// Channel callback used for channels opened with `confirm_select`; routes
+// `basic.ack`/`basic.nack` for a given delivery tag to the waiter registered
+// by `publish_confirmed`, then falls back to the default behavior for
+// everything else.
+#[derive(Clone)]
+struct ConfirmCallback {
+    pending: Arc<Mutex<PendingConfirms>>,
+}
+
+impl ConfirmCallback {
+    fn new() -> Self {
+        Self {
+            pending: Arc::new(Mutex::new(PendingConfirms::default())),
+        }
+    }
+
+    async fn resolve(&self, delivery_tag: u64, multiple: bool, outcome: ConfirmOutcome) {
+        let mut pending = self.pending.lock().await;
+        if multiple {
+            let tags: Vec<u64> = pending.waiters.keys().copied().filter(|t| *t <= delivery_tag).collect();
+            for tag in tags {
+                if let Some(waiter) = pending.waiters.remove(&tag) {
+                    let _ = waiter.send(outcome);
+                }
+            }
+        } else if let Some(waiter) = pending.waiters.remove(&delivery_tag) {
+            let _ = waiter.send(outcome);
+        }
+    }
+}
+
+#[async_trait]
+impl ChannelCallback for ConfirmCallback {
+    async fn close(&mut self, channel: &Channel, reason: amqprs::error::Error) -> std::result::Result<(), amqprs::error::Error> {
+        DefaultChannelCallback.close(channel, reason).await
+    }
+
+    async fn cancel(&mut self, channel: &Channel, consumer_tag: &str) -> std::result::Result<(), amqprs::error::Error> {
+        DefaultChannelCallback.cancel(channel, consumer_tag).await
+    }
+
+    async fn flow(&mut self, channel: &Channel, active: bool) -> std::result::Result<bool, amqprs::error::Error> {
+        DefaultChannelCallback.flow(channel, active).await
+    }
+
+    async fn publish_ack(&mut self, _channel: &Channel, delivery_tag: u64, multiple: bool) {
+        self.resolve(delivery_tag, multiple, ConfirmOutcome::Ack).await;
+    }
+
+    async fn publish_nack(&mut self, _channel: &Channel, delivery_tag: u64, multiple: bool) {
+        warn!("Broker nacked publish (delivery_tag={}, multiple={})", delivery_tag, multiple);
+        self.resolve(delivery_tag, multiple, ConfirmOutcome::Nack).await;
+    }
+
+    async fn publish_return(&mut self, _channel: &Channel, reply_code: i16, reply_text: String, exchange: String, routing_key: String, _basic_properties: BasicProperties, _content: Vec<u8>) {
+        warn!("Publish returned unroutable (code={}, text={}, exchange={}, routing_key={})", reply_code, reply_text, exchange, routing_key);
+    }
+}
+
 // Message sent when a slot becomes available and a user can confirm their booking
 #[derive(Debug, Serialize, Deserialize)]
 struct SlotAvailableMessage {
@@ -32,6 +193,54 @@ struct SlotAvailableMessage {
     confirmation_deadline: DateTime<Utc>,
 }
 
+// Compact payload pushed over MQTT when a slot opens up. The user is already
+// addressed via the topic (`waitlist/<user_id>/slot-available`), so unlike
+// `SlotAvailableMessage` this doesn't repeat `user_id` in the body.
+#[derive(Debug, Serialize)]
+struct MqttSlotAvailablePayload {
+    booking_id: i32,
+    conference_name: String,
+    confirmation_deadline: DateTime<Utc>,
+}
+
+// Optional push-notification bridge to an MQTT broker (modeled on rumqttc's
+// async client), used to nudge a waiting user's device the moment their slot
+// opens - AMQP alone has no path to the client, only to backend consumers.
+// `AsyncClient` is a cheap, cloneable publish handle; the paired `EventLoop`
+// must be polled continuously by a background task to drive the connection,
+// so `initialize` spawns that loop once and only ever hands out clones of
+// this handle.
+#[derive(Clone)]
+struct MqttHandle {
+    client: rumqttc::AsyncClient,
+}
+
+impl MqttHandle {
+    // Publishes the slot-available payload at QoS 1 and swallows failures -
+    // a missed push notification is not worth blocking or rolling back the
+    // AMQP-driven confirmation-pending state that already owns the booking.
+    async fn notify_slot_available(&self, user_id: &str, booking_id: i32, conference_name: &str, confirmation_deadline: DateTime<Utc>) {
+        let topic = format!("waitlist/{}/slot-available", user_id);
+        let payload = MqttSlotAvailablePayload {
+            booking_id,
+            conference_name: conference_name.to_string(),
+            confirmation_deadline,
+        };
+
+        let body = match serde_json::to_vec(&payload) {
+            Ok(body) => body,
+            Err(e) => {
+                warn!("Failed to serialize MQTT slot-available payload for booking {}: {:?}", booking_id, e);
+                return;
+            }
+        };
+
+        if let Err(e) = self.client.publish(&topic, rumqttc::QoS::AtLeastOnce, false, body).await {
+            warn!("Failed to publish MQTT slot-available notification to '{}': {:?}", topic, e);
+        }
+    }
+}
+
 // Message for confirmation expiration tracking
 #[derive(Debug, Serialize, Deserialize)]
 struct ConfirmationExpirationMessage {
@@ -40,6 +249,59 @@ struct ConfirmationExpirationMessage {
     conference_name: String,
 }
 
+// Forwards `booking_update` deliveries from a `subscribe_booking_updates`
+// queue to the channel the WebSocket/SSE handler is reading from. Content is
+// appended to `buffer` rather than assumed to be one complete JSON object
+// per delivery - the same defensive stance a polling client has to take
+// against its transport - so invalid UTF-8 or a malformed frame is logged
+// and dropped without tearing down the rest of the subscription.
+struct BookingUpdateFanoutConsumer {
+    buffer: Vec<u8>,
+    sender: mpsc::Sender<BookingUpdateEvent>,
+}
+
+#[async_trait::async_trait]
+impl AsyncConsumer for BookingUpdateFanoutConsumer {
+    async fn consume(&mut self, channel: &Channel, deliver: Deliver, _basic_properties: BasicProperties, content: Vec<u8>) {
+        self.buffer.extend_from_slice(&content);
+
+        loop {
+            let text = match std::str::from_utf8(&self.buffer) {
+                Ok(text) => text,
+                Err(e) => {
+                    warn!("Dropping non-UTF-8 booking update frame ({} bytes): {:?}", self.buffer.len(), e);
+                    self.buffer.clear();
+                    break;
+                }
+            };
+
+            let mut stream = serde_json::Deserializer::from_str(text).into_iter::<BookingUpdateEvent>();
+            match stream.next() {
+                Some(Ok(event)) => {
+                    let consumed = stream.byte_offset();
+                    self.buffer.drain(..consumed);
+                    if self.sender.send(event).await.is_err() {
+                        // Subscriber dropped - nothing left to forward to.
+                        return;
+                    }
+                }
+                // Incomplete frame - wait for the next delivery to complete it.
+                Some(Err(e)) if e.is_eof() => break,
+                Some(Err(e)) => {
+                    warn!("Dropping unparseable booking update frame: {:?}", e);
+                    self.buffer.clear();
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        if let Err(e) = channel.basic_ack(BasicAckArguments::new(deliver.delivery_tag(), false)).await {
+            error!("Error acknowledging booking update delivery: {:?}", e);
+        }
+    }
+}
+
 // Message for conference start events
 #[derive(Debug, Serialize, Deserialize)]
 struct ConferenceStartMessage {
@@ -50,11 +312,76 @@ struct ConferenceStartMessage {
 // Consumer for handling expired confirmation messages
 struct ExpiredConfirmationConsumer {
     db_pool: DbPool,
+    // Used to open a fresh, dedicated confirm-mode channel per auto-promotion
+    // publish (see `publish_timer_confirmed`). The consuming channel itself
+    // isn't confirm-mode: it also carries `broadcast_booking_event`,
+    // `park_message` and retry nacks, so a single shared confirm sequence
+    // would desynchronize the broker's delivery tags from the one publish we
+    // actually need to wait on.
+    connection: Arc<Connection>,
+    queue_name: String,
+    parking_queue: String,
+    max_retries: i64,
+    // Present only when `WaitlistQueueService` was configured with
+    // `with_mqtt_broker`; auto-promotions push a notification through it the
+    // same way `publish_slot_available` does for the manually-triggered path.
+    mqtt: Option<MqttHandle>,
+    metrics: QueueMetrics,
+    // How long the newly-promoted booking gets to confirm, mirroring
+    // `WaitlistQueueService::confirmation_deadline_secs` for the
+    // manually-triggered `publish_slot_available` path.
+    confirmation_deadline_secs: i64,
+    // Shared with `WaitlistQueueService::booking_events`, so a booking moved
+    // back to the waitlist or auto-promoted here reaches any SSE connection
+    // subscribed to it via `subscribe_booking_events`.
+    booking_events: Arc<DashMap<i32, broadcast::Sender<BookingStatusResponse>>>,
+    // Exchange `broadcast_booking_event` publishes the AMQP side of a booking
+    // update to, mirroring `WaitlistQueueService::booking_exchange`.
+    booking_exchange: String,
+    // Shared conference/booking lookup cache - invalidated here wherever a
+    // booking moved back to the waitlist or got auto-promoted, mirroring
+    // `WaitlistQueueService::cache`.
+    cache: crate::cache::LookupCache,
+    // Present only when `WaitlistQueueService` was configured with SMTP
+    // settings; auto-promotions email the user the same way
+    // `publish_slot_available` does for the manually-triggered path.
+    notifier: Option<Arc<dyn crate::notifier::Notifier>>,
+    // How `promote_next_waitlisted_person` delays its confirmation-expiry
+    // message, mirroring `WaitlistQueueService::delay_strategy`.
+    delay_strategy: DelayStrategy,
+    // Queue the `DlxTtl` strategy publishes the expiry message to, mirroring
+    // `WaitlistQueueService::confirmation_timer_queue`.
+    confirmation_timer_queue: String,
+    // `x-delayed-message` exchange used when `delay_strategy` is
+    // `DelayedExchange`, mirroring `WaitlistQueueService::delayed_exchange`.
+    delayed_exchange: String,
+    // Dead letter exchange the `DlxTtl` strategy's timer queue routes an
+    // expired message back through, mirroring
+    // `WaitlistQueueService::dead_letter_exchange`. `queue_name` above is
+    // already this consumer's `dead_letter_queue`.
+    dead_letter_exchange: String,
 }
 
 impl ExpiredConfirmationConsumer {
-    fn new(db_pool: DbPool) -> Self {
-        Self { db_pool }
+    fn new(db_pool: DbPool, connection: Arc<Connection>, queue_name: String, parking_queue: String, max_retries: i64, mqtt: Option<MqttHandle>, metrics: QueueMetrics, confirmation_deadline_secs: i64, booking_events: Arc<DashMap<i32, broadcast::Sender<BookingStatusResponse>>>, booking_exchange: String, cache: crate::cache::LookupCache, notifier: Option<Arc<dyn crate::notifier::Notifier>>, delay_strategy: DelayStrategy, confirmation_timer_queue: String, delayed_exchange: String, dead_letter_exchange: String) -> Self {
+        Self {
+            db_pool,
+            connection,
+            queue_name,
+            parking_queue,
+            max_retries,
+            mqtt,
+            metrics,
+            confirmation_deadline_secs,
+            booking_events,
+            booking_exchange,
+            cache,
+            notifier,
+            delay_strategy,
+            confirmation_timer_queue,
+            delayed_exchange,
+            dead_letter_exchange,
+        }
     }
 }
 
@@ -62,29 +389,188 @@ impl ExpiredConfirmationConsumer {
 struct ConferenceStartConsumer {
     db_pool: DbPool,
     waitlist_queue_prefix: String,
+    queue_name: String,
+    parking_queue: String,
+    max_retries: i64,
+    metrics: QueueMetrics,
 }
 
 impl ConferenceStartConsumer {
-    fn new(db_pool: DbPool, waitlist_queue_prefix: String) -> Self {
-        Self { 
+    fn new(db_pool: DbPool, waitlist_queue_prefix: String, queue_name: String, parking_queue: String, max_retries: i64, metrics: QueueMetrics) -> Self {
+        Self {
             db_pool,
-            waitlist_queue_prefix 
+            waitlist_queue_prefix,
+            queue_name,
+            parking_queue,
+            max_retries,
+            metrics,
         }
     }
 }
 
+// Shared redelivery policy for the dead-letter-driven consumers: messages are
+// retried a bounded number of times via a DLX -> short-TTL retry queue ->
+// back-to-original loop (so RabbitMQ's own `x-death` header array tracks the
+// attempt count for us), then parked rather than requeued forever once they
+// exceed `max_retries`.
+async fn requeue_or_park(
+    channel: &Channel,
+    deliver: &Deliver,
+    basic_properties: &BasicProperties,
+    content: &[u8],
+    original_queue: &str,
+    parking_queue: &str,
+    max_retries: i64,
+    reason: &str,
+    metrics: &QueueMetrics,
+) -> Result<()> {
+    let retry_count = x_death_retry_count(basic_properties, original_queue);
+
+    if retry_count < max_retries {
+        warn!(
+            "Requeuing message from '{}' via retry queue (attempt {}/{}): {}",
+            original_queue, retry_count + 1, max_retries, reason
+        );
+        // requeue=false: the queue's DLX routes this to the retry queue
+        // rather than putting it straight back at the head of this queue.
+        channel.basic_nack(BasicNackArguments::new(deliver.delivery_tag(), false, false)).await?;
+        return Ok(());
+    }
+
+    error!(
+        "Message from '{}' exceeded {} retries, parking to '{}': {}",
+        original_queue, max_retries, parking_queue, reason
+    );
+    park_message(channel, deliver, content, original_queue, parking_queue, reason, metrics).await
+}
+
+// Moves a message straight to its parking queue (with the failure reason and
+// originating queue recorded in the headers) and acks the original delivery.
+// Used once retries are exhausted, and for messages that can never succeed
+// regardless of retries (e.g. malformed payloads).
+async fn park_message(
+    channel: &Channel,
+    deliver: &Deliver,
+    content: &[u8],
+    original_queue: &str,
+    parking_queue: &str,
+    reason: &str,
+    metrics: &QueueMetrics,
+) -> Result<()> {
+    let mut headers = FieldTable::new();
+    headers.insert("x-failure-reason".try_into()?, reason.into());
+    headers.insert("x-original-queue".try_into()?, original_queue.into());
+
+    let properties = BasicProperties::default()
+        .with_delivery_mode(2)
+        .with_headers(headers)
+        .finish();
+
+    channel
+        .basic_publish(properties, content.to_vec(), BasicPublishArguments::new("", parking_queue))
+        .await?;
+
+    // Ack the original delivery - it's been preserved in the parking queue,
+    // so there's nothing left for this queue to redeliver.
+    channel.basic_ack(BasicAckArguments::new(deliver.delivery_tag(), false)).await?;
+    metrics.parked_messages.inc();
+
+    Ok(())
+}
+
+// Pushes a booking-status-change event to any SSE connections currently
+// subscribed to `event.booking_id` (see
+// `WaitlistQueueService::subscribe_booking_events`), and best-effort
+// publishes the same event - wrapped in a `BookingUpdateEvent` envelope - to
+// `booking_exchange` under routing key `booking.{booking_id}`, for
+// `subscribe_booking_updates`'s per-connection AMQP queue (backing the
+// WebSocket endpoint, and any future out-of-process subscriber). A failed
+// in-process send just means nobody happens to be subscribed right now,
+// which isn't worth logging; a failed AMQP publish is logged and swallowed
+// rather than propagated, since the in-process broadcast above already
+// delivered the update to same-process subscribers. Shared between
+// `WaitlistQueueService` and `ExpiredConfirmationConsumer`, the two places a
+// booking's status/can_confirm/waitlist_position changes.
+async fn broadcast_booking_event(
+    booking_events: &DashMap<i32, broadcast::Sender<BookingStatusResponse>>,
+    channel: &Channel,
+    booking_exchange: &str,
+    event: BookingStatusResponse,
+) {
+    if let Some(sender) = booking_events.get(&event.booking_id) {
+        let _ = sender.send(event.clone());
+    }
+
+    let booking_id = event.booking_id;
+    match serde_json::to_vec(&crate::models::BookingUpdateEvent::new(event)) {
+        Ok(content) => {
+            let args = BasicPublishArguments::new(booking_exchange, &format!("booking.{}", booking_id));
+            if let Err(e) = channel.basic_publish(BasicProperties::default(), content, args).await {
+                warn!("Failed to publish booking update for booking {} to '{}': {:?}", booking_id, booking_exchange, e);
+            }
+        }
+        Err(e) => warn!("Failed to serialize booking update for booking {}: {:?}", booking_id, e),
+    }
+}
+
+// Reads how many times this message has already been dead-lettered through
+// `original_queue`'s retry loop, via the `x-death` header array RabbitMQ
+// maintains automatically.
+fn x_death_retry_count(properties: &BasicProperties, original_queue: &str) -> i64 {
+    let headers = match properties.headers() {
+        Some(h) => h,
+        None => return 0,
+    };
+
+    let deaths = match headers.get("x-death") {
+        Some(amqprs::FieldValue::FieldArray(arr)) => arr,
+        _ => return 0,
+    };
+
+    for entry in deaths.iter() {
+        if let amqprs::FieldValue::FieldTable(death) = entry {
+            let is_this_queue = matches!(
+                death.get("queue"),
+                Some(amqprs::FieldValue::LongString(q)) if q.to_string() == original_queue
+            );
+            if is_this_queue {
+                if let Some(amqprs::FieldValue::SignedLong(count)) = death.get("count") {
+                    return *count;
+                }
+            }
+        }
+    }
+
+    0
+}
+
 #[async_trait::async_trait]
 impl AsyncConsumer for ExpiredConfirmationConsumer {
     async fn consume(
         &mut self,
         channel: &Channel,
         deliver: Deliver,
-        _basic_properties: BasicProperties,
+        basic_properties: BasicProperties,
         content: Vec<u8>,
     ) {
+        use tracing::Instrument;
+
+        // Continue the distributed trace started by whichever publish put
+        // this message here (waitlist add -> promotion -> this expiry), if
+        // a `traceparent` was propagated in the message headers.
+        let span = tracing::info_span!(
+            "handle_expired_confirmation",
+            booking_id = tracing::field::Empty,
+            conference_name = tracing::field::Empty
+        );
+        crate::telemetry::set_parent_from_headers(&span, basic_properties.headers());
+
         info!("🔄 Processing expired confirmation message");
-        
-        let result = self.handle_expired_confirmation(channel, deliver, content).await;
+
+        let result = self
+            .handle_expired_confirmation(channel, deliver, basic_properties, content)
+            .instrument(span)
+            .await;
         if let Err(e) = result {
             error!("❌ Failed to process expired confirmation: {:?}", e);
         }
@@ -97,12 +583,20 @@ impl AsyncConsumer for ConferenceStartConsumer {
         &mut self,
         channel: &Channel,
         deliver: Deliver,
-        _basic_properties: BasicProperties,
+        basic_properties: BasicProperties,
         content: Vec<u8>,
     ) {
+        use tracing::Instrument;
+
+        let span = tracing::info_span!("handle_conference_start", conference_name = tracing::field::Empty);
+        crate::telemetry::set_parent_from_headers(&span, basic_properties.headers());
+
         info!("🏁 Processing conference start event");
-        
-        let result = self.handle_conference_start(channel, deliver, content).await;
+
+        let result = self
+            .handle_conference_start(channel, deliver, basic_properties, content)
+            .instrument(span)
+            .await;
         if let Err(e) = result {
             error!("❌ Failed to process conference start event: {:?}", e);
         }
@@ -110,23 +604,66 @@ impl AsyncConsumer for ConferenceStartConsumer {
 }
 
 impl ExpiredConfirmationConsumer {
-    async fn handle_expired_confirmation(&mut self, channel: &Channel, deliver: Deliver, content: Vec<u8>) -> Result<()> {
+    // Publishes a single message on a fresh channel opened in confirm mode
+    // just for this send, and waits for the broker's ack/nack - mirroring
+    // `WaitlistQueueService::get_confirmed_channel`/`publish_confirmed`'s
+    // FIRST_DELIVERY_TAG=1 invariant (a channel that carries exactly one
+    // publish is always waiting on delivery tag 1), rather than sharing a
+    // confirm sequence with the consuming channel's other publishes.
+    async fn publish_timer_confirmed(&self, exchange: &str, routing_key: &str, properties: BasicProperties, content: Vec<u8>) -> Result<ConfirmOutcome> {
+        const FIRST_DELIVERY_TAG: u64 = 1;
+
+        let channel = self.connection.open_channel(None).await?;
+        let confirm_callback = ConfirmCallback::new();
+        let pending = confirm_callback.pending.clone();
+        channel.register_callback(confirm_callback).await?;
+        channel.confirm_select(ConfirmSelectArguments::new(false)).await?;
+
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut pending = pending.lock().await;
+            pending.waiters.insert(FIRST_DELIVERY_TAG, tx);
+        }
+
+        let args = BasicPublishArguments::new(exchange, routing_key);
+        if let Err(e) = channel.basic_publish(properties, content, args).await {
+            let _ = channel.close().await;
+            return Err(e.into());
+        }
+
+        let outcome = tokio::time::timeout(PUBLISH_CONFIRM_TIMEOUT, rx).await;
+        let _ = channel.close().await;
+
+        match outcome {
+            Ok(Ok(outcome)) => Ok(outcome),
+            Ok(Err(_)) | Err(_) => {
+                Err(Box::new(PublishConfirmError::TimedOut).into())
+            }
+        }
+    }
+
+    async fn handle_expired_confirmation(&mut self, channel: &Channel, deliver: Deliver, basic_properties: BasicProperties, content: Vec<u8>) -> Result<()> {
         match serde_json::from_slice::<ConfirmationExpirationMessage>(&content) {
             Ok(message) => {
+                let span = tracing::Span::current();
+                span.record("booking_id", message.booking_id);
+                span.record("conference_name", message.conference_name.as_str());
+
                 info!("⏰ Confirmation expired for booking {} from conference {}", message.booking_id, message.conference_name);
-                
+
                 match self.db_pool.get() {
                     Ok(mut conn) => {
                         // Move booking back to end of waitlist
-                        match self.move_booking_to_waitlist_end(&mut conn, message.booking_id, &message.conference_name).await {
+                        match self.move_booking_to_waitlist_end(&mut conn, channel, message.booking_id, &message.conference_name).await {
                             Ok(true) => {
                                 info!("✅ Moved booking {} back to waitlist for conference {}", message.booking_id, message.conference_name);
-                                
+                                self.metrics.expired_confirmations_processed.inc();
+
                                 // 🔥 CRITICAL FIX: Automatically promote next person in line
                                 if let Err(e) = self.promote_next_waitlisted_person(&mut conn, &message.conference_name, channel).await {
                                     error!("❌ Failed to promote next waitlisted person for '{}': {:?}", message.conference_name, e);
                                 }
-                                
+
                                 // Acknowledge successful processing
                                 if let Err(e) = channel.basic_ack(BasicAckArguments::new(deliver.delivery_tag(), false)).await {
                                     error!("Error acknowledging message: {:?}", e);
@@ -134,6 +671,7 @@ impl ExpiredConfirmationConsumer {
                             },
                             Ok(false) => {
                                 info!("ℹ️ Booking {} was not in confirmation pending state", message.booking_id);
+                                self.metrics.expired_confirmations_processed.inc();
                                 // Acknowledge - not an error condition
                                 if let Err(e) = channel.basic_ack(BasicAckArguments::new(deliver.delivery_tag(), false)).await {
                                     error!("Error acknowledging message: {:?}", e);
@@ -141,9 +679,12 @@ impl ExpiredConfirmationConsumer {
                             },
                             Err(e) => {
                                 error!("❌ Error processing expired confirmation: {:?}", e);
-                                // Reject and requeue for retry
-                                if let Err(e) = channel.basic_nack(BasicNackArguments::new(deliver.delivery_tag(), false, true)).await {
-                                    error!("Error rejecting message: {:?}", e);
+                                if let Err(park_err) = requeue_or_park(
+                                    channel, &deliver, &basic_properties, &content,
+                                    &self.queue_name, &self.parking_queue, self.max_retries, &e.to_string(),
+                                    &self.metrics,
+                                ).await {
+                                    error!("Error requeuing/parking message: {:?}", park_err);
                                 }
                                 return Err(e);
                             }
@@ -151,9 +692,12 @@ impl ExpiredConfirmationConsumer {
                     },
                     Err(e) => {
                         error!("❌ Database connection error: {:?}", e);
-                        // Reject and requeue
-                        if let Err(e) = channel.basic_nack(BasicNackArguments::new(deliver.delivery_tag(), false, true)).await {
-                            error!("Error rejecting message: {:?}", e);
+                        if let Err(park_err) = requeue_or_park(
+                            channel, &deliver, &basic_properties, &content,
+                            &self.queue_name, &self.parking_queue, self.max_retries, &e.to_string(),
+                            &self.metrics,
+                        ).await {
+                            error!("Error requeuing/parking message: {:?}", park_err);
                         }
                         return Err(e.into());
                     }
@@ -161,19 +705,19 @@ impl ExpiredConfirmationConsumer {
             },
             Err(e) => {
                 error!("❌ Error deserializing expired confirmation message: {:?}", e);
-                // Reject without requeue - malformed message
-                if let Err(e) = channel.basic_nack(BasicNackArguments::new(deliver.delivery_tag(), false, false)).await {
-                    error!("Error rejecting message: {:?}", e);
+                // Malformed message - no number of retries will fix it
+                if let Err(park_err) = park_message(channel, &deliver, &content, &self.queue_name, &self.parking_queue, &format!("deserialize error: {}", e), &self.metrics).await {
+                    error!("Error parking message: {:?}", park_err);
                 }
                 return Err(e.into());
             }
         }
-        
+
         Ok(())
     }
 
     // Move booking back to end of waitlist
-    async fn move_booking_to_waitlist_end(&self, conn: &mut PgConnection, booking_id: i32, conference_name: &str) -> Result<bool> {
+    async fn move_booking_to_waitlist_end(&self, conn: &mut PgConnection, channel: &Channel, booking_id: i32, conference_name: &str) -> Result<bool> {
         use crate::actions::{get_conference_by_name};
         
         // Get conference ID
@@ -199,7 +743,20 @@ impl ExpiredConfirmationConsumer {
                 bookings::waitlist_position.eq(new_position),
             ))
             .execute(conn)?;
-            
+
+        if updated > 0 {
+            self.cache.invalidate_booking(booking_id);
+
+            broadcast_booking_event(&self.booking_events, channel, &self.booking_exchange, BookingStatusResponse {
+                booking_id,
+                status: BookingStatus::WAITLISTED,
+                conference_name: conference_name.to_string(),
+                can_confirm: false,
+                confirmation_deadline: None,
+                waitlist_position: Some(new_position),
+            }).await;
+        }
+
         Ok(updated > 0)
     }
     
@@ -225,9 +782,8 @@ impl ExpiredConfirmationConsumer {
             .optional()?;
         
         if let Some(booking) = next_waitlisted {
-            // Set confirmation deadline to 10 seconds
-            let deadline = Utc::now() + Duration::seconds(10);
-            
+            let deadline = Utc::now() + Duration::seconds(self.confirmation_deadline_secs);
+
             // Update booking in database - set confirmation pending
             diesel::update(bookings::table)
                 .filter(bookings::booking_id.eq(booking.booking_id))
@@ -246,21 +802,122 @@ impl ExpiredConfirmationConsumer {
                 conference_name: conference_name.to_string(),
             };
             
-            // Publish message to confirmation timer queue with 10-second TTL
+            // Schedule it for delivery to the dead-letter queue after the
+            // configured deadline, via whichever `DelayStrategy` is configured -
+            // mirroring `WaitlistQueueService::publish_slot_available`'s own
+            // promotion path, rather than hardcoding the `DlxTtl` queue name.
             let serialized = serde_json::to_string(&expiration_msg)?;
             let content = serialized.as_bytes().to_vec();
-            
-            let properties = BasicProperties::default()
-                .with_delivery_mode(2) // persistent
-                .with_expiration("10000") // 10 seconds in milliseconds
-                .finish();
-            
-            let args = BasicPublishArguments::new("", "confirmation.timer");
-            
-            channel.basic_publish(properties, content, args).await?;
-            
-            info!("🔄 Auto-promoted booking {} from waitlist for conference '{}' (slots available: {}). Confirmation expires in 10 seconds at {}", 
-                  booking.booking_id, conference_name, conference.available_slots, deadline);
+            let delay_ms = self.confirmation_deadline_secs * 1000;
+
+            if self.delay_strategy == DelayStrategy::DlxTtl {
+                // Declare the confirmation timer queue with dead letter exchange
+                let mut args = FieldTable::new();
+                args.insert("x-dead-letter-exchange".try_into()?, self.dead_letter_exchange.clone().into());
+                args.insert("x-dead-letter-routing-key".try_into()?, self.queue_name.clone().into());
+
+                channel
+                    .queue_declare(
+                        QueueDeclareArguments::new(&self.confirmation_timer_queue)
+                            .durable(true)
+                            .arguments(args)
+                            .finish(),
+                    )
+                    .await?;
+            } else {
+                let mut exchange_args = FieldTable::new();
+                exchange_args.insert("x-delayed-type".try_into()?, "direct".into());
+                channel
+                    .exchange_declare(
+                        amqprs::channel::ExchangeDeclareArguments::new(&self.delayed_exchange, "x-delayed-message")
+                            .durable(true)
+                            .arguments(exchange_args)
+                            .finish(),
+                    )
+                    .await?;
+                // Bind the destination queue directly to the delayed
+                // exchange - once the plugin's per-message timer fires, it
+                // routes there like a normal direct-exchange publish.
+                channel
+                    .queue_bind(QueueBindArguments::new(&self.queue_name, &self.delayed_exchange, &self.queue_name))
+                    .await?;
+            }
+
+            let mut headers = FieldTable::new();
+            crate::telemetry::inject_trace_context(&mut headers);
+
+            let (publish_exchange, publish_routing_key, properties) = match self.delay_strategy {
+                DelayStrategy::DlxTtl => (
+                    "".to_string(),
+                    self.confirmation_timer_queue.clone(),
+                    BasicProperties::default()
+                        .with_delivery_mode(2) // persistent
+                        .with_expiration(&delay_ms.to_string())
+                        .with_headers(headers)
+                        .finish(),
+                ),
+                DelayStrategy::DelayedExchange => {
+                    headers.insert("x-delay".try_into()?, delay_ms.into());
+                    (
+                        self.delayed_exchange.clone(),
+                        self.queue_name.clone(),
+                        BasicProperties::default()
+                            .with_delivery_mode(2) // persistent
+                            .with_headers(headers)
+                            .finish(),
+                    )
+                }
+            };
+
+            // Publish on a fresh confirm-mode channel dedicated to this one
+            // message, rather than sharing a confirm sequence with the
+            // consuming channel's other publishes (see
+            // `publish_timer_confirmed`).
+            let publish_result = self.publish_timer_confirmed(&publish_exchange, &publish_routing_key, properties, content).await;
+
+            match publish_result {
+                Ok(ConfirmOutcome::Ack) => {
+                    info!("🔄 Auto-promoted booking {} from waitlist for conference '{}' (slots available: {}). Confirmation expires at {}",
+                          booking.booking_id, conference_name, conference.available_slots, deadline);
+                    self.metrics.waitlist_promotions.inc();
+                    self.cache.invalidate_booking(booking.booking_id);
+
+                    broadcast_booking_event(&self.booking_events, channel, &self.booking_exchange, BookingStatusResponse {
+                        booking_id: booking.booking_id,
+                        status: BookingStatus::ConfirmationPending,
+                        conference_name: conference_name.to_string(),
+                        can_confirm: true,
+                        confirmation_deadline: Some(deadline.naive_utc()),
+                        waitlist_position: None,
+                    }).await;
+
+                    if let Some(mqtt) = &self.mqtt {
+                        let user_id = booking.user_id.clone().unwrap_or_default();
+                        mqtt.notify_slot_available(&user_id, booking.booking_id, conference_name, deadline).await;
+                    }
+
+                    if let Some(notifier) = &self.notifier {
+                        let user_id = booking.user_id.clone().unwrap_or_default();
+                        notifier.notify_slot_available(&user_id, booking.booking_id, conference_name, deadline).await;
+                    }
+                }
+                _ => {
+                    // The broker never confirmed delivery of the expiration timer
+                    // message, so roll the promotion back rather than leave the
+                    // booking "pending" with nothing that will ever time it out.
+                    error!("❌ Confirmation timer publish for booking {} was not confirmed by the broker - reverting promotion", booking.booking_id);
+                    diesel::update(bookings::table)
+                        .filter(bookings::booking_id.eq(booking.booking_id))
+                        .set((
+                            bookings::status.eq(BookingStatus::WAITLISTED),
+                            bookings::can_confirm.eq(false),
+                            bookings::waitlist_confirmation_deadline.eq::<Option<chrono::NaiveDateTime>>(None),
+                            bookings::waitlist_position.eq(booking.waitlist_position),
+                        ))
+                        .execute(conn)?;
+                    self.cache.invalidate_booking(booking.booking_id);
+                }
+            }
         } else {
             info!("ℹ️ No more waitlisted bookings for conference '{}' - waitlist exhausted", conference_name);
         }
@@ -270,21 +927,21 @@ impl ExpiredConfirmationConsumer {
 }
 
 impl ConferenceStartConsumer {
-    async fn handle_conference_start(&mut self, channel: &Channel, deliver: Deliver, content: Vec<u8>) -> Result<()> {
+    async fn handle_conference_start(&mut self, channel: &Channel, deliver: Deliver, basic_properties: BasicProperties, content: Vec<u8>) -> Result<()> {
         match serde_json::from_slice::<ConferenceStartMessage>(&content) {
             Ok(message) => {
                 info!("🚀 Conference '{}' has started at {}", message.conference_name, message.start_time);
-                
+
                 match self.db_pool.get() {
                     Ok(mut conn) => {
                         // Cancel all waitlisted bookings for this conference
                         match self.process_conference_start(&mut conn, &message.conference_name, channel).await {
                             Ok(cancelled_count) => {
                                 if cancelled_count > 0 {
-                                    info!("✅ Cancelled {} waitlisted bookings and cleaned up queue for conference '{}'", 
+                                    info!("✅ Cancelled {} waitlisted bookings and cleaned up queue for conference '{}'",
                                           cancelled_count, message.conference_name);
                                 }
-                                
+
                                 // Acknowledge successful processing
                                 if let Err(e) = channel.basic_ack(BasicAckArguments::new(deliver.delivery_tag(), false)).await {
                                     error!("Error acknowledging message: {:?}", e);
@@ -292,9 +949,12 @@ impl ConferenceStartConsumer {
                             },
                             Err(e) => {
                                 error!("❌ Error processing conference start: {:?}", e);
-                                // Reject and requeue for retry
-                                if let Err(e) = channel.basic_nack(BasicNackArguments::new(deliver.delivery_tag(), false, true)).await {
-                                    error!("Error rejecting message: {:?}", e);
+                                if let Err(park_err) = requeue_or_park(
+                                    channel, &deliver, &basic_properties, &content,
+                                    &self.queue_name, &self.parking_queue, self.max_retries, &e.to_string(),
+                                    &self.metrics,
+                                ).await {
+                                    error!("Error requeuing/parking message: {:?}", park_err);
                                 }
                                 return Err(e);
                             }
@@ -302,9 +962,12 @@ impl ConferenceStartConsumer {
                     },
                     Err(e) => {
                         error!("❌ Database connection error: {:?}", e);
-                        // Reject and requeue
-                        if let Err(e) = channel.basic_nack(BasicNackArguments::new(deliver.delivery_tag(), false, true)).await {
-                            error!("Error rejecting message: {:?}", e);
+                        if let Err(park_err) = requeue_or_park(
+                            channel, &deliver, &basic_properties, &content,
+                            &self.queue_name, &self.parking_queue, self.max_retries, &e.to_string(),
+                            &self.metrics,
+                        ).await {
+                            error!("Error requeuing/parking message: {:?}", park_err);
                         }
                         return Err(e.into());
                     }
@@ -312,14 +975,14 @@ impl ConferenceStartConsumer {
             },
             Err(e) => {
                 error!("❌ Error deserializing conference start message: {:?}", e);
-                // Reject without requeue - malformed message
-                if let Err(e) = channel.basic_nack(BasicNackArguments::new(deliver.delivery_tag(), false, false)).await {
-                    error!("Error rejecting message: {:?}", e);
+                // Malformed message - no number of retries will fix it
+                if let Err(park_err) = park_message(channel, &deliver, &content, &self.queue_name, &self.parking_queue, &format!("deserialize error: {}", e), &self.metrics).await {
+                    error!("Error parking message: {:?}", park_err);
                 }
                 return Err(e.into());
             }
         }
-        
+
         Ok(())
     }
 
@@ -377,34 +1040,224 @@ pub struct WaitlistQueueService {
     dead_letter_exchange: String,
     dead_letter_queue: String,
     conference_start_queue: String,
+    conference_start_timer_queue: String,
+    // Strategy used to delay confirmation-expiry/conference-start messages;
+    // see `DelayStrategy`.
+    delay_strategy: DelayStrategy,
+    delayed_exchange: String,
+    // How long a promoted booking has to confirm before its slot is
+    // released back to the waitlist, sourced from `Settings`. Threaded into
+    // `Duration::seconds(..)` and the AMQP message TTL for the confirmation
+    // timer instead of the old hardcoded "10 seconds for testing".
+    confirmation_deadline_secs: i64,
+    // Max entries a single conference's waitlist queue may hold before the
+    // broker starts rejecting publishes (`x-overflow: reject-publish`).
+    conference_waitlist_capacity: i64,
+    // How many times a poison message is allowed to loop through a queue's
+    // DLX -> retry-queue -> back-to-original cycle before it's parked.
+    max_redeliveries: i64,
+    // `basic.qos` prefetch applied to the confirmation/conference-start
+    // consumers, bounding how many unacked deliveries the broker will push at
+    // once so a burst of expirations can't exhaust `db_pool`.
+    consumer_prefetch_count: u16,
+    // Broker address for the optional MQTT push-notification bridge, set via
+    // `with_mqtt_broker`. `None` means the bridge is disabled and `mqtt`
+    // below stays `None` after `initialize`.
+    mqtt_broker: Option<(String, u16)>,
+    mqtt: Option<MqttHandle>,
+    // Email bridge built from `Settings::smtp_*` in `with_waitlist_capacity`.
+    // `None` when `smtp_host` is empty, the same convention `mqtt` uses for
+    // the optional MQTT bridge - promotion just skips sending an email.
+    notifier: Option<Arc<dyn crate::notifier::Notifier>>,
+    metrics: QueueMetrics,
+    // GCRA rate limiter, keyed by conference name, guarding publish-side
+    // queue operations (`add_to_waitlist`, `publish_slot_available`) so one
+    // conference's churn can't flood RabbitMQ/the DB pool for everyone else.
+    publish_rate_limiter: Arc<ConferenceRateLimiter>,
+    // OTLP collector endpoint for exported trace spans, set via
+    // `with_otlp_endpoint`. `None` means spans are only printed locally via
+    // the `fmt` tracing layer.
+    otlp_endpoint: Option<String>,
+    // RabbitMQ connection details, sourced from `Settings` instead of the old
+    // hardcoded `"localhost", 5672, "guest", "guest"`.
+    amqp_host: String,
+    amqp_port: u16,
+    amqp_username: String,
+    amqp_password: String,
+    // Per-booking-id broadcast channels backing the `GET
+    // /booking/{booking_id}/events` SSE stream, created lazily by
+    // `subscribe_booking_events`. The expired-confirmations consumer and the
+    // slot-available promotion path both push onto these via
+    // `broadcast_booking_event` whenever they mutate a booking's
+    // status/can_confirm/waitlist_position.
+    booking_events: Arc<DashMap<i32, broadcast::Sender<BookingStatusResponse>>>,
+    // Shared conference/booking lookup cache - invalidated here wherever a
+    // consumer mutates a booking's waitlist position or promotes it to
+    // confirmation-pending, so `main.rs`'s handlers never serve a stale hit.
+    cache: crate::cache::LookupCache,
 }
 
 impl WaitlistQueueService {
-    pub fn new(db_pool: DbPool) -> Self {
+    pub fn new(db_pool: DbPool, settings: Settings, cache: crate::cache::LookupCache) -> Self {
+        Self::with_waitlist_capacity(db_pool, settings, DEFAULT_WAITLIST_CAPACITY, cache)
+    }
+
+    pub fn with_waitlist_capacity(db_pool: DbPool, settings: Settings, conference_waitlist_capacity: i64, cache: crate::cache::LookupCache) -> Self {
+        let notifier: Option<Arc<dyn crate::notifier::Notifier>> = if settings.smtp_host.is_empty() {
+            None
+        } else {
+            match crate::notifier::SmtpNotifier::new(&settings, db_pool.clone()) {
+                Ok(notifier) => Some(Arc::new(notifier)),
+                Err(e) => {
+                    error!("Failed to build SMTP notifier, booking notifications will be skipped: {:?}", e);
+                    None
+                }
+            }
+        };
+
         Self {
             db_pool,
             connection: None,
             channel_pool: Arc::new(Mutex::new(Vec::new())),
-            max_channels: 10, // Smaller pool for stability
-            conference_exchange: "conference.events".to_string(),
-            booking_exchange: "booking.events".to_string(),
-            waitlist_queue_prefix: "conference.".to_string(),
-            confirmation_timer_queue: "confirmation.timer".to_string(),
-            dead_letter_exchange: "dead.letter.exchange".to_string(),
-            dead_letter_queue: "confirmation.expired".to_string(),
-            conference_start_queue: "conference.starts".to_string(),
+            max_channels: settings.max_channels,
+            conference_exchange: settings.conference_exchange,
+            booking_exchange: settings.booking_exchange,
+            waitlist_queue_prefix: settings.waitlist_queue_prefix,
+            confirmation_timer_queue: settings.confirmation_timer_queue,
+            dead_letter_exchange: settings.dead_letter_exchange,
+            dead_letter_queue: settings.dead_letter_queue,
+            conference_start_queue: settings.conference_start_queue,
+            conference_start_timer_queue: settings.conference_start_timer_queue,
+            delay_strategy: settings.delay_strategy,
+            delayed_exchange: settings.delayed_exchange,
+            confirmation_deadline_secs: settings.confirmation_deadline_secs,
+            conference_waitlist_capacity,
+            max_redeliveries: DEFAULT_MAX_REDELIVERIES,
+            consumer_prefetch_count: 10, // same as the default `Settings::max_channels`
+            mqtt_broker: None,
+            mqtt: None,
+            notifier,
+            metrics: QueueMetrics::new(),
+            publish_rate_limiter: Arc::new(RateLimiter::dashmap(Self::default_publish_quota())),
+            otlp_endpoint: None,
+            amqp_host: settings.amqp_host,
+            amqp_port: settings.amqp_port,
+            amqp_username: settings.amqp_username,
+            amqp_password: settings.amqp_password,
+            booking_events: Arc::new(DashMap::new()),
+            cache,
         }
     }
-    
+
+    // Sets the OTLP collector endpoint spans are exported to; call before
+    // `initialize`, which is what actually starts the tracing pipeline.
+    // Deployments that never call this still get spans, just not exported.
+    pub fn with_otlp_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.otlp_endpoint = Some(endpoint.into());
+        self
+    }
+
+    fn default_publish_quota() -> Quota {
+        Quota::per_second(std::num::NonZeroU32::new(DEFAULT_PUBLISH_RATE_PER_SECOND).unwrap())
+            .allow_burst(std::num::NonZeroU32::new(DEFAULT_PUBLISH_BURST).unwrap())
+    }
+
+    // Overrides the default per-conference publish quota (`DEFAULT_PUBLISH_RATE_PER_SECOND`
+    // promotions/sec with a burst of `DEFAULT_PUBLISH_BURST`). Call before `initialize`.
+    pub fn with_publish_quota(mut self, per_second: u32, burst: u32) -> Self {
+        let per_second = std::num::NonZeroU32::new(per_second).unwrap_or(std::num::NonZeroU32::new(1).unwrap());
+        let burst = std::num::NonZeroU32::new(burst).unwrap_or(std::num::NonZeroU32::new(1).unwrap());
+        self.publish_rate_limiter = Arc::new(RateLimiter::dashmap(Quota::per_second(per_second).allow_burst(burst)));
+        self
+    }
+
+    // Enables the optional MQTT push-notification bridge; call before
+    // `initialize`, which opens the connection and spawns its event loop.
+    // Deployments that never call this skip MQTT entirely - `mqtt` stays
+    // `None` and promotion paths just don't send a push notification.
+    pub fn with_mqtt_broker(mut self, host: impl Into<String>, port: u16) -> Self {
+        self.mqtt_broker = Some((host.into(), port));
+        self
+    }
+
+    // Hands out the email notifier built from `Settings::smtp_*` at
+    // construction time, e.g. for `main` to pass to
+    // `notifier::run_confirmation_reminder_scheduler`. `None` if
+    // `smtp_host` was empty or the transport failed to build.
+    pub fn notifier(&self) -> Option<Arc<dyn crate::notifier::Notifier>> {
+        self.notifier.clone()
+    }
+
+    // Hands out a clone of the service's metrics registry, e.g. for `main`
+    // to start the `/metrics` HTTP endpoint alongside the queue consumers.
+    pub fn metrics(&self) -> QueueMetrics {
+        self.metrics.clone()
+    }
+
+    // Subscribes to status-change events for `booking_id`, creating its
+    // broadcast channel on first use. Used by the `GET
+    // /booking/{booking_id}/events` SSE handler - the connection holds onto
+    // the returned receiver for as long as it stays open.
+    pub fn subscribe_booking_events(&self, booking_id: i32) -> broadcast::Receiver<BookingStatusResponse> {
+        self.booking_events
+            .entry(booking_id)
+            .or_insert_with(|| broadcast::channel(BOOKING_EVENT_CHANNEL_CAPACITY).0)
+            .subscribe()
+    }
+
+    // AMQP-backed counterpart to `subscribe_booking_events`, used by the
+    // `GET /booking/{booking_id}/ws` WebSocket endpoint: declares a private,
+    // auto-deleted queue bound to `booking_exchange` under routing key
+    // `booking.{booking_id}` (the key `broadcast_booking_event` publishes
+    // under), and hands back a channel fed by a `BookingUpdateFanoutConsumer`
+    // consuming it. Unlike `subscribe_booking_events`, this path survives a
+    // booking update originating from a different process sharing the same
+    // broker.
+    pub async fn subscribe_booking_updates(&self, booking_id: i32) -> Result<mpsc::Receiver<BookingUpdateEvent>> {
+        let channel = self.get_fresh_channel().await?;
+
+        let queue_name = format!("booking.updates.{}.{}", booking_id, Uuid::new_v4());
+        channel
+            .queue_declare(
+                QueueDeclareArguments::new(&queue_name)
+                    .exclusive(true)
+                    .auto_delete(true)
+                    .finish(),
+            )
+            .await?;
+
+        channel
+            .queue_bind(QueueBindArguments::new(
+                &queue_name,
+                &self.booking_exchange,
+                &format!("booking.{}", booking_id),
+            ))
+            .await?;
+
+        let (tx, rx) = mpsc::channel(BOOKING_EVENT_CHANNEL_CAPACITY);
+        let consumer = BookingUpdateFanoutConsumer { buffer: Vec::new(), sender: tx };
+        let consumer_tag = format!("booking_update_consumer_{}", Uuid::new_v4());
+        channel
+            .basic_consume(consumer, BasicConsumeArguments::new(&queue_name, &consumer_tag).manual_ack(true).finish())
+            .await?;
+
+        Ok(rx)
+    }
+
     pub async fn initialize(&mut self) -> Result<()> {
+        // Bring up the tracing pipeline first so every span below (including
+        // the ones `#[tracing::instrument]` attaches to the publish methods)
+        // is captured from the start.
+        crate::telemetry::init(self.otlp_endpoint.as_deref());
+
         info!("Connecting to RabbitMQ with amqprs (improved)...");
-        
+
         // Connect to RabbitMQ
         let connection = Connection::open(&OpenConnectionArguments::new(
-            "localhost", 
-            5672,
-            "guest", 
-            "guest",
+            &self.amqp_host,
+            self.amqp_port,
+            &self.amqp_username,
+            &self.amqp_password,
         )).await?;
         
         connection
@@ -453,15 +1306,25 @@ impl WaitlistQueueService {
             )
             .await?;
             
-        // Declare the dead letter queue
+        // Declare the dead letter queue, routing permanently-nacked deliveries
+        // (requeue=false) to its retry queue rather than discarding them, so
+        // transient failures get bounded redelivery instead of looping forever
+        // at the broker's "redeliver immediately" default.
+        let retry_queue = format!("{}.retry", self.dead_letter_queue);
+        let parking_queue = format!("{}.parking", self.dead_letter_queue);
+        let mut dlq_args = FieldTable::new();
+        dlq_args.insert("x-dead-letter-exchange".try_into()?, "".into());
+        dlq_args.insert("x-dead-letter-routing-key".try_into()?, retry_queue.clone().into());
+
         setup_channel
             .queue_declare(
                 QueueDeclareArguments::new(&self.dead_letter_queue)
                     .durable(true)
+                    .arguments(dlq_args)
                     .finish(),
             )
             .await?;
-            
+
         // Bind dead letter queue to dead letter exchange
         setup_channel
             .queue_bind(
@@ -473,7 +1336,33 @@ impl WaitlistQueueService {
                 .finish(),
             )
             .await?;
-            
+
+        // Retry queue: holds a message for RETRY_QUEUE_TTL_MS then dead-letters
+        // it back to the original queue for another attempt.
+        let mut retry_args = FieldTable::new();
+        retry_args.insert("x-message-ttl".try_into()?, RETRY_QUEUE_TTL_MS.into());
+        retry_args.insert("x-dead-letter-exchange".try_into()?, "".into());
+        retry_args.insert("x-dead-letter-routing-key".try_into()?, self.dead_letter_queue.clone().into());
+
+        setup_channel
+            .queue_declare(
+                QueueDeclareArguments::new(&retry_queue)
+                    .durable(true)
+                    .arguments(retry_args)
+                    .finish(),
+            )
+            .await?;
+
+        // Parking queue: final resting place for messages that exceeded
+        // max_redeliveries, for operators to inspect manually.
+        setup_channel
+            .queue_declare(
+                QueueDeclareArguments::new(&parking_queue)
+                    .durable(true)
+                    .finish(),
+            )
+            .await?;
+
         // Declare the confirmation timer queue with dead letter exchange
         let mut args = FieldTable::new();
         args.insert(
@@ -494,15 +1383,45 @@ impl WaitlistQueueService {
             )
             .await?;
             
-        // Declare the conference start queue
+        // Declare the conference start queue, with the same bounded-retry
+        // DLX/TTL loop as the confirmation dead letter queue above.
+        let conference_start_retry_queue = format!("{}.retry", self.conference_start_queue);
+        let conference_start_parking_queue = format!("{}.parking", self.conference_start_queue);
+        let mut start_queue_args = FieldTable::new();
+        start_queue_args.insert("x-dead-letter-exchange".try_into()?, "".into());
+        start_queue_args.insert("x-dead-letter-routing-key".try_into()?, conference_start_retry_queue.clone().into());
+
         setup_channel
             .queue_declare(
                 QueueDeclareArguments::new(&self.conference_start_queue)
                     .durable(true)
+                    .arguments(start_queue_args)
                     .finish(),
             )
             .await?;
-            
+
+        let mut conference_start_retry_args = FieldTable::new();
+        conference_start_retry_args.insert("x-message-ttl".try_into()?, RETRY_QUEUE_TTL_MS.into());
+        conference_start_retry_args.insert("x-dead-letter-exchange".try_into()?, "".into());
+        conference_start_retry_args.insert("x-dead-letter-routing-key".try_into()?, self.conference_start_queue.clone().into());
+
+        setup_channel
+            .queue_declare(
+                QueueDeclareArguments::new(&conference_start_retry_queue)
+                    .durable(true)
+                    .arguments(conference_start_retry_args)
+                    .finish(),
+            )
+            .await?;
+
+        setup_channel
+            .queue_declare(
+                QueueDeclareArguments::new(&conference_start_parking_queue)
+                    .durable(true)
+                    .finish(),
+            )
+            .await?;
+
         // Bind conference start queue to conference exchange
         setup_channel
             .queue_bind(
@@ -516,43 +1435,222 @@ impl WaitlistQueueService {
             .await?;
         
         self.connection = Some(Arc::new(connection));
-        
+
         // Close the setup channel since we'll use pooled channels
         let _ = setup_channel.close().await;
-        
+
         info!("Connected to RabbitMQ with amqprs and initialized queues");
-        
+
+        // Connect the optional MQTT push-notification bridge, if configured.
+        if let Some((host, port)) = self.mqtt_broker.clone() {
+            let mut mqtt_options = rumqttc::MqttOptions::new(format!("waitlist-api-{}", Uuid::new_v4()), host, port);
+            mqtt_options.set_keep_alive(std::time::Duration::from_secs(30));
+
+            let (client, mut eventloop) = rumqttc::AsyncClient::new(mqtt_options, 16);
+
+            // The event loop has to be polled continuously to drive the
+            // connection (reconnects, pings, etc.) - this task does nothing
+            // with the yielded events since we only ever publish.
+            tokio::spawn(async move {
+                loop {
+                    if let Err(e) = eventloop.poll().await {
+                        error!("MQTT event loop error: {:?}", e);
+                        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+                    }
+                }
+            });
+
+            self.mqtt = Some(MqttHandle { client });
+            info!("Connected MQTT push-notification bridge");
+        }
+
         Ok(())
     }
     
+    // Declares the `x-delayed-message` exchange used by the `DelayedExchange`
+    // strategy. Idempotent - safe to call on every publish.
+    async fn declare_delayed_exchange(&self, channel: &Channel) -> Result<()> {
+        let mut args = FieldTable::new();
+        args.insert("x-delayed-type".try_into()?, "direct".into());
+
+        channel
+            .exchange_declare(
+                amqprs::channel::ExchangeDeclareArguments::new(&self.delayed_exchange, "x-delayed-message")
+                    .durable(true)
+                    .arguments(args)
+                    .finish(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // Schedules `content` for delivery to `destination_queue` after
+    // `delay_ms`, via whichever `DelayStrategy` the service is configured
+    // with. `timer_queue` is only used by the `DlxTtl` strategy and must
+    // already be declared with a DLX routing key back to `destination_queue`.
+    async fn publish_delayed(
+        &self,
+        channel: &Channel,
+        timer_queue: &str,
+        destination_queue: &str,
+        delay_ms: i64,
+        content: Vec<u8>,
+        mut headers: FieldTable,
+    ) -> Result<()> {
+        match self.delay_strategy {
+            DelayStrategy::DlxTtl => {
+                let properties = BasicProperties::default()
+                    .with_delivery_mode(2) // persistent
+                    .with_expiration(&delay_ms.to_string())
+                    .with_headers(headers)
+                    .finish();
+
+                channel
+                    .basic_publish(properties, content, BasicPublishArguments::new("", timer_queue))
+                    .await?;
+            }
+            DelayStrategy::DelayedExchange => {
+                self.declare_delayed_exchange(channel).await?;
+                // Bind the destination queue directly to the delayed
+                // exchange - once the plugin's per-message timer fires, it
+                // routes there like a normal direct-exchange publish.
+                channel
+                    .queue_bind(QueueBindArguments::new(destination_queue, &self.delayed_exchange, destination_queue))
+                    .await?;
+
+                headers.insert("x-delay".try_into()?, delay_ms.into());
+                let properties = BasicProperties::default()
+                    .with_delivery_mode(2) // persistent
+                    .with_headers(headers)
+                    .finish();
+
+                channel
+                    .basic_publish(properties, content, BasicPublishArguments::new(&self.delayed_exchange, destination_queue))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     // Get a fresh channel with retry logic
     async fn get_fresh_channel(&self) -> Result<Channel> {
         if let Some(connection) = &self.connection {
             let channel = connection.open_channel(None).await?;
             channel.register_callback(DefaultChannelCallback).await?;
-            
+
             // Small delay to ensure channel is fully ready
             tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
-            
+            self.metrics.channel_pool_occupancy.set(self.channel_pool.lock().await.len() as i64);
+
             Ok(channel)
         } else {
             Err("RabbitMQ connection not initialized".into())
         }
     }
     
-    // Robust queue operation that handles failures gracefully with retry
-    async fn safe_queue_operation<F, Fut>(&self, operation: F) -> Result<()>
+    // Open a fresh channel in publisher-confirm mode, with a `ConfirmCallback`
+    // registered so `publish_confirmed` can await the broker's ack/nack.
+    async fn get_confirmed_channel(&self) -> Result<(Channel, Arc<Mutex<PendingConfirms>>)> {
+        if let Some(connection) = &self.connection {
+            let channel = connection.open_channel(None).await?;
+            let callback = ConfirmCallback::new();
+            let pending = callback.pending.clone();
+            channel.register_callback(callback).await?;
+            channel.confirm_select(ConfirmSelectArguments::new(false)).await?;
+
+            tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
+            self.metrics.channel_pool_occupancy.set(self.channel_pool.lock().await.len() as i64);
+
+            Ok((channel, pending))
+        } else {
+            Err("RabbitMQ connection not initialized".into())
+        }
+    }
+
+    // Publish a single message in confirm mode and wait for the broker's
+    // `basic.ack`/`basic.nack`. If the broker nacks the publish or doesn't
+    // respond within `PUBLISH_CONFIRM_TIMEOUT`, the DB mutation that was made
+    // durable-on-the-assumption-of-delivery (e.g. flipping a booking to
+    // `ConfirmationPending`) is rolled back via `rollback` so the caller's
+    // state stays consistent with what was actually published.
+    async fn publish_confirmed<R>(
+        &self,
+        exchange: &str,
+        routing_key: &str,
+        properties: BasicProperties,
+        content: Vec<u8>,
+        rollback: R,
+    ) -> Result<()>
+    where
+        R: FnOnce(&mut PgConnection) -> Result<()>,
+    {
+        let (channel, pending) = self.get_confirmed_channel().await?;
+
+        // amqprs numbers confirms starting at 1 on a freshly confirm-selected
+        // channel; since each confirmed channel here carries exactly one
+        // publish, the delivery tag we're waiting on is always 1.
+        const FIRST_DELIVERY_TAG: u64 = 1;
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut pending = pending.lock().await;
+            pending.waiters.insert(FIRST_DELIVERY_TAG, tx);
+        }
+
+        let args = BasicPublishArguments::new(exchange, routing_key);
+        if let Err(e) = channel.basic_publish(properties, content, args).await {
+            let _ = channel.close().await;
+            return Err(e.into());
+        }
+
+        let outcome = tokio::time::timeout(PUBLISH_CONFIRM_TIMEOUT, rx).await;
+        let _ = channel.close().await;
+
+        match outcome {
+            Ok(Ok(ConfirmOutcome::Ack)) => Ok(()),
+            Ok(Ok(ConfirmOutcome::Nack)) => {
+                error!("Broker nacked confirmed publish to '{}/{}' - rolling back", exchange, routing_key);
+                let mut conn = self.db_pool.get()?;
+                rollback(&mut conn)?;
+                Err(Box::new(PublishConfirmError::Nacked).into())
+            }
+            Ok(Err(_)) | Err(_) => {
+                error!("Timed out waiting for publish confirm on '{}/{}' - rolling back", exchange, routing_key);
+                let mut conn = self.db_pool.get()?;
+                rollback(&mut conn)?;
+                Err(Box::new(PublishConfirmError::TimedOut).into())
+            }
+        }
+    }
+
+    // Robust queue operation that handles failures gracefully with retry.
+    // `operation_name` labels the `queue_operations_total` counter and times
+    // the whole retry loop in `queue_operation_duration`.
+    async fn safe_queue_operation<F, Fut>(&self, operation_name: &str, operation: F) -> Result<()>
     where
         F: Fn() -> Fut + Clone,
         Fut: std::future::Future<Output = Result<()>>,
     {
+        let _timer = self.metrics.queue_operation_duration.start_timer();
         let max_retries = 2;
         let mut delay_ms = 25;
-        
+
         for attempt in 1..=max_retries {
             match operation().await {
-                Ok(_) => return Ok(()),
+                Ok(_) => {
+                    self.metrics.queue_operations.with_label_values(&[operation_name, "ok"]).inc();
+                    return Ok(());
+                }
                 Err(e) => {
+                    // A typed `QueueError` (e.g. a full waitlist) isn't a
+                    // transient broker hiccup - surface it immediately instead
+                    // of retrying and then swallowing it below.
+                    if e.downcast_ref::<QueueError>().is_some() {
+                        self.metrics.queue_operations.with_label_values(&[operation_name, "err"]).inc();
+                        return Err(e);
+                    }
+
                     if attempt < max_retries {
                         warn!("Queue operation failed (attempt {}/{}), retrying: {:?}", attempt, max_retries, e);
                         // Wait before retrying with exponential backoff
@@ -560,17 +1658,19 @@ impl WaitlistQueueService {
                         delay_ms *= 2;
                     } else {
                         error!("Queue operation failed after {} attempts, giving up: {:?}", max_retries, e);
+                        self.metrics.queue_operations.with_label_values(&[operation_name, "err"]).inc();
                         // Don't propagate the error - queue failures shouldn't block booking operations
                         return Ok(());
                     }
                 }
             }
         }
-        
+
         Ok(())
     }
     
     // Add a booking to the waitlist
+    #[tracing::instrument(skip(self, booking), fields(booking_id = booking.booking_id, conference_name = %conference_name))]
     pub async fn add_to_waitlist(&self, booking: &Booking, conference_name: &str) -> Result<()> {
         let booking_clone = booking.clone();
         let conference_name_clone = conference_name.to_string();
@@ -581,17 +1681,31 @@ impl WaitlistQueueService {
             let service = self.clone();
             
             async move {
+                // Enforce the per-conference publish quota before doing any
+                // broker work, so a bursty conference can't starve others.
+                if service.publish_rate_limiter.check_key(&conference_name).is_err() {
+                    warn!("Rate limit exceeded for conference '{}' on add_to_waitlist", conference_name);
+                    return Err(Box::new(QueueError::RateLimited) as Box<dyn std::error::Error + Send + Sync>);
+                }
+
                 // Get a fresh channel for this operation
                 let channel = service.get_fresh_channel().await?;
-                
-                // Ensure the conference waitlist queue exists
+
+                // Ensure the conference waitlist queue exists, bounded so a
+                // conference's waitlist can't grow without limit. Once it's
+                // full the broker rejects further publishes instead of
+                // silently queueing them.
                 let queue_name = format!("{}{}.waitlist", service.waitlist_queue_prefix, conference_name);
-                
-                // Declare the queue
+
+                let mut queue_args = FieldTable::new();
+                queue_args.insert("x-max-length".try_into()?, service.conference_waitlist_capacity.into());
+                queue_args.insert("x-overflow".try_into()?, "reject-publish".into());
+
                 channel
                     .queue_declare(
                         QueueDeclareArguments::new(&queue_name)
                             .durable(true)
+                            .arguments(queue_args)
                             .finish(),
                     )
                     .await?;
@@ -604,37 +1718,45 @@ impl WaitlistQueueService {
                     confirmation_deadline: Utc::now(),
                 };
                 
+                // Close the queue-declaring channel; the confirmed publish below
+                // opens its own channel in confirm mode.
+                let _ = channel.close().await;
+
                 // Publish message to waitlist queue
                 let serialized = serde_json::to_string(&message)?;
                 let content = serialized.as_bytes().to_vec();
-                
+
+                let mut headers = FieldTable::new();
+                crate::telemetry::inject_trace_context(&mut headers);
                 let properties = BasicProperties::default()
                     .with_delivery_mode(2) // persistent
+                    .with_headers(headers)
                     .finish();
-                
-                // Publish directly to the queue
-                let args = BasicPublishArguments::new("", &queue_name);
-                
-                channel
-                    .basic_publish(
-                        properties,
-                        content,
-                        args,
-                    )
-                    .await?;
-                
-                // Close the channel after use
-                let _ = channel.close().await;
-                
+
+                // Publish with publisher confirms so we know the broker actually
+                // accepted the message before reporting this operation a success.
+                // There's no DB mutation to undo here (the booking is already
+                // WAITLISTED), so the rollback is a no-op.
+                service.publish_confirmed("", &queue_name, properties, content, |_conn| Ok(()))
+                    .await
+                    .map_err(|e| {
+                        if e.downcast_ref::<PublishConfirmError>() == Some(&PublishConfirmError::Nacked) {
+                            Box::new(QueueError::WaitlistFull) as Box<dyn std::error::Error + Send + Sync>
+                        } else {
+                            e
+                        }
+                    })?;
+
                 info!("Added booking {} to waitlist for conference {}", booking.booking_id, conference_name);
                 Ok(())
             }
         };
         
-        self.safe_queue_operation(operation).await
+        self.safe_queue_operation("add_to_waitlist", operation).await
     }
     
     // Publish message when a slot becomes available
+    #[tracing::instrument(skip(self), fields(conference_name = %conference_name))]
     pub async fn publish_slot_available(&self, conference_name: &str) -> Result<()> {
         let conference_name_clone = conference_name.to_string();
         
@@ -643,9 +1765,16 @@ impl WaitlistQueueService {
             let service = self.clone();
             
             async move {
+                // Enforce the per-conference publish quota before doing any
+                // broker/DB work, so a bursty conference can't starve others.
+                if service.publish_rate_limiter.check_key(&conference_name).is_err() {
+                    warn!("Rate limit exceeded for conference '{}' on publish_slot_available", conference_name);
+                    return Err(Box::new(QueueError::RateLimited) as Box<dyn std::error::Error + Send + Sync>);
+                }
+
                 // Get a fresh channel for this operation
                 let channel = service.get_fresh_channel().await?;
-                
+
                 // Get conference and check available slots first
                 let mut conn = service.db_pool.get()?;
                 
@@ -677,9 +1806,8 @@ impl WaitlistQueueService {
                     .optional()?;
                 
                 if let Some(booking) = next_waitlisted {
-                    // Set confirmation deadline to 10 seconds for testing
-                    let deadline = Utc::now() + Duration::seconds(10);
-                    
+                    let deadline = Utc::now() + Duration::seconds(service.confirmation_deadline_secs);
+
                     // Update booking in database - set confirmation pending
                     diesel::update(bookings::table)
                         .filter(bookings::booking_id.eq(booking.booking_id))
@@ -690,57 +1818,129 @@ impl WaitlistQueueService {
                             bookings::waitlist_position.eq(None::<i32>),
                         ))
                         .execute(&mut conn)?;
-                    
-                    // Declare the confirmation timer queue with dead letter exchange
-                    let mut args = FieldTable::new();
-                    args.insert(
-                        "x-dead-letter-exchange".try_into()?,
-                        service.dead_letter_exchange.clone().into()
-                    );
-                    args.insert(
-                        "x-dead-letter-routing-key".try_into()?,
-                        "confirmation.expired".into()
-                    );
-                    
-                    channel
-                        .queue_declare(
-                            QueueDeclareArguments::new(&service.confirmation_timer_queue)
-                                .durable(true)
-                                .arguments(args)
-                                .finish(),
-                        )
-                        .await?;
-                    
+
+                    service.cache.invalidate_booking(booking.booking_id);
+
+                    let delay_ms = service.confirmation_deadline_secs * 1000;
+
+                    if service.delay_strategy == DelayStrategy::DlxTtl {
+                        // Declare the confirmation timer queue with dead letter exchange
+                        let mut args = FieldTable::new();
+                        args.insert(
+                            "x-dead-letter-exchange".try_into()?,
+                            service.dead_letter_exchange.clone().into()
+                        );
+                        args.insert(
+                            "x-dead-letter-routing-key".try_into()?,
+                            service.dead_letter_queue.clone().into()
+                        );
+
+                        channel
+                            .queue_declare(
+                                QueueDeclareArguments::new(&service.confirmation_timer_queue)
+                                    .durable(true)
+                                    .arguments(args)
+                                    .finish(),
+                            )
+                            .await?;
+                    } else {
+                        service.declare_delayed_exchange(&channel).await?;
+                        // Bind the destination queue directly to the delayed
+                        // exchange - once the plugin's per-message timer fires, it
+                        // routes there like a normal direct-exchange publish.
+                        channel
+                            .queue_bind(QueueBindArguments::new(&service.dead_letter_queue, &service.delayed_exchange, &service.dead_letter_queue))
+                            .await?;
+                    }
+
                     // Create confirmation expiration message
                     let expiration_msg = ConfirmationExpirationMessage {
                         booking_id: booking.booking_id,
                         expiration_time: deadline,
                         conference_name: conference_name.clone(),
                     };
-                    
-                    // Publish message to confirmation timer queue with 10-second TTL
+
+                    // Schedule it for delivery to the dead-letter queue after the
+                    // configured deadline, via whichever `DelayStrategy` is configured.
                     let serialized = serde_json::to_string(&expiration_msg)?;
                     let content = serialized.as_bytes().to_vec();
-                    
-                    let properties = BasicProperties::default()
-                        .with_delivery_mode(2) // persistent
-                        .with_expiration("10000") // 10 seconds in milliseconds
-                        .finish();
-                    
-                    let args: BasicPublishArguments = BasicPublishArguments::new("", &service.confirmation_timer_queue);
-                    
-                    channel
-                        .basic_publish(
-                            properties,
-                            content,
-                            args,
-                        )
+
+                    let mut headers = FieldTable::new();
+                    crate::telemetry::inject_trace_context(&mut headers);
+
+                    let (publish_exchange, publish_routing_key, properties) = match service.delay_strategy {
+                        DelayStrategy::DlxTtl => (
+                            "".to_string(),
+                            service.confirmation_timer_queue.clone(),
+                            BasicProperties::default()
+                                .with_delivery_mode(2) // persistent
+                                .with_expiration(&delay_ms.to_string())
+                                .with_headers(headers)
+                                .finish(),
+                        ),
+                        DelayStrategy::DelayedExchange => {
+                            headers.insert("x-delay".try_into()?, delay_ms.into());
+                            (
+                                service.delayed_exchange.clone(),
+                                service.dead_letter_queue.clone(),
+                                BasicProperties::default()
+                                    .with_delivery_mode(2) // persistent
+                                    .with_headers(headers)
+                                    .finish(),
+                            )
+                        }
+                    };
+
+                    // Publish the expiration timer through the same
+                    // confirm/rollback path as `add_to_waitlist`'s publish, so a
+                    // broker nack or timeout reverts this promotion back to
+                    // WAITLISTED instead of leaving it stuck in
+                    // `ConfirmationPending` with no timer that will ever fire -
+                    // mirroring the auto-promotion path's rollback in
+                    // `ExpiredConfirmationConsumer::promote_next_waitlisted_person`.
+                    let rollback_booking_id = booking.booking_id;
+                    let rollback_position = booking.waitlist_position;
+                    let rollback_cache = service.cache.clone();
+                    service
+                        .publish_confirmed(&publish_exchange, &publish_routing_key, properties, content, move |conn| {
+                            diesel::update(bookings::table)
+                                .filter(bookings::booking_id.eq(rollback_booking_id))
+                                .set((
+                                    bookings::status.eq(BookingStatus::WAITLISTED),
+                                    bookings::can_confirm.eq(false),
+                                    bookings::waitlist_confirmation_deadline.eq::<Option<chrono::NaiveDateTime>>(None),
+                                    bookings::waitlist_position.eq(rollback_position),
+                                ))
+                                .execute(conn)?;
+                            rollback_cache.invalidate_booking(rollback_booking_id);
+                            Ok(())
+                        })
                         .await?;
-                    
+
                     info!(
-                        "📢 Promoted booking {} from waitlist for conference '{}' (slots available: {}). Confirmation expires in 10 seconds at {}", 
+                        "📢 Promoted booking {} from waitlist for conference '{}' (slots available: {}). Confirmation expires at {}",
                         booking.booking_id, conference_name, conference.available_slots, deadline
                     );
+                    service.metrics.waitlist_promotions.inc();
+
+                    broadcast_booking_event(&service.booking_events, &channel, &service.booking_exchange, BookingStatusResponse {
+                        booking_id: booking.booking_id,
+                        status: BookingStatus::ConfirmationPending,
+                        conference_name: conference_name.clone(),
+                        can_confirm: true,
+                        confirmation_deadline: Some(deadline.naive_utc()),
+                        waitlist_position: None,
+                    }).await;
+
+                    if let Some(mqtt) = &service.mqtt {
+                        let user_id = booking.user_id.clone().unwrap_or_default();
+                        mqtt.notify_slot_available(&user_id, booking.booking_id, &conference_name, deadline).await;
+                    }
+
+                    if let Some(notifier) = &service.notifier {
+                        let user_id = booking.user_id.clone().unwrap_or_default();
+                        notifier.notify_slot_available(&user_id, booking.booking_id, &conference_name, deadline).await;
+                    }
                 } else {
                     info!("No waitlisted bookings found for conference '{}'", conference_name);
                 }
@@ -751,7 +1951,7 @@ impl WaitlistQueueService {
             }
         };
         
-        self.safe_queue_operation(operation).await
+        self.safe_queue_operation("publish_slot_available", operation).await
     }
     
     // Start consuming messages from the dead letter queue to handle expired confirmations
@@ -761,19 +1961,22 @@ impl WaitlistQueueService {
             info!("📋 Dead letter queue name: {}", self.dead_letter_queue);
             
             let channel = connection.open_channel(None).await?;
-            channel.register_callback(DefaultChannelCallback).await?;
-            
+            // Cap in-flight unacked deliveries so a burst of expirations can't
+            // exhaust the DB pool with concurrent blocking work.
+            channel.basic_qos(BasicQosArguments::new(0, self.consumer_prefetch_count, false)).await?;
+
             let db_pool = self.db_pool.clone();
             let dead_letter_queue = self.dead_letter_queue.clone();
-            
+            let parking_queue = format!("{}.parking", dead_letter_queue);
+
             // Start consuming messages with manual ack
             let consumer_tag = format!("expired_confirmation_consumer_{}", Uuid::new_v4());
             let args = BasicConsumeArguments::new(&dead_letter_queue, &consumer_tag)
                 .manual_ack(true)
                 .finish();
-            
+
             // Create a simple consumer to process messages
-            let consumer = ExpiredConfirmationConsumer::new(db_pool);
+            let consumer = ExpiredConfirmationConsumer::new(db_pool, connection.clone(), dead_letter_queue.clone(), parking_queue, self.max_redeliveries, self.mqtt.clone(), self.metrics.clone(), self.confirmation_deadline_secs, self.booking_events.clone(), self.booking_exchange.clone(), self.cache.clone(), self.notifier.clone(), self.delay_strategy, self.confirmation_timer_queue.clone(), self.delayed_exchange.clone(), self.dead_letter_exchange.clone());
             
             tokio::spawn(async move {
                 info!("⚡ Started consuming expired confirmation messages");
@@ -805,23 +2008,29 @@ impl WaitlistQueueService {
             
             let channel = connection.open_channel(None).await?;
             channel.register_callback(DefaultChannelCallback).await?;
-            
+            // Cap in-flight unacked deliveries for the same reason as the
+            // expired-confirmations consumer above.
+            channel.basic_qos(BasicQosArguments::new(0, self.consumer_prefetch_count, false)).await?;
+
             let db_pool = self.db_pool.clone();
             let waitlist_queue_prefix = self.waitlist_queue_prefix.clone();
             let conference_start_queue = self.conference_start_queue.clone();
-            
+            let parking_queue = format!("{}.parking", conference_start_queue);
+            let max_redeliveries = self.max_redeliveries;
+            let metrics = self.metrics.clone();
+
             // Start consuming from conference.starts queue
             tokio::spawn(async move {
                 info!("⚡ Started conference start event consumer on queue: {}", conference_start_queue);
-                
+
                 let consumer_tag = format!("conference_start_consumer_{}", Uuid::new_v4());
                 let args = BasicConsumeArguments::new(&conference_start_queue, &consumer_tag)
                     .manual_ack(true)
                     .finish();
-                
+
                 // Create consumer for conference start events
-                let consumer = ConferenceStartConsumer::new(db_pool, waitlist_queue_prefix);
-                
+                let consumer = ConferenceStartConsumer::new(db_pool, waitlist_queue_prefix, conference_start_queue.clone(), parking_queue, max_redeliveries, metrics);
+
                 match channel.basic_consume(consumer, args).await {
                     Ok(_) => {
                         info!("✅ Conference start event consumer started successfully");
@@ -842,6 +2051,49 @@ impl WaitlistQueueService {
         Ok(())
     }
 
+    // Holds a dedicated `LISTEN slot_available` connection and calls
+    // `publish_slot_available` for every notification, so waitlist
+    // promotion is event-driven off the `conferences_notify_slot_available`
+    // DB trigger rather than relying on every code path that frees a slot
+    // remembering to call `publish_slot_available` itself.
+    pub async fn start_listening_slot_changes(&self) -> Result<()> {
+        let conn_spec = std::env::var("DATABASE_URL")?;
+        let service = self.clone();
+
+        let (client, connection) = tokio_postgres::connect(&conn_spec, NoTls).await?;
+        let mut notifications = futures_util::stream::poll_fn(move |cx| connection.poll_message(cx));
+
+        client.batch_execute(&format!("LISTEN {}", SLOT_AVAILABLE_CHANNEL)).await?;
+        info!("👂 Listening for '{}' notifications", SLOT_AVAILABLE_CHANNEL);
+
+        tokio::spawn(async move {
+            // Keep `client` alive for the life of the task - dropping it
+            // would close the LISTEN connection.
+            let _client = client;
+
+            while let Some(message) = notifications.next().await {
+                match message {
+                    Ok(AsyncMessage::Notification(notification)) => {
+                        let conference_name = notification.payload().to_string();
+                        info!("🔔 Received slot_available notification for conference '{}'", conference_name);
+                        if let Err(e) = service.publish_slot_available(&conference_name).await {
+                            error!("Failed to publish slot_available for '{}': {:?}", conference_name, e);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        error!("❌ LISTEN connection for '{}' errored: {:?}", SLOT_AVAILABLE_CHANNEL, e);
+                        break;
+                    }
+                }
+            }
+
+            warn!("LISTEN connection for '{}' closed", SLOT_AVAILABLE_CHANNEL);
+        });
+
+        Ok(())
+    }
+
     // Add a booking to waitlist by booking ID
     pub async fn add_to_waitlist_by_booking_id(&self, booking_id: i32, conference_name: &str) -> Result<()> {
         let mut conn = self.db_pool.get()?;
@@ -855,6 +2107,7 @@ impl WaitlistQueueService {
     }
 
     // Publish conference start event when a conference is created
+    #[tracing::instrument(skip(self), fields(conference_name = %conference_name, ttl_seconds = tracing::field::Empty))]
     pub async fn schedule_conference_start_event(&self, conference_name: &str, start_time: DateTime<Utc>) -> Result<()> {
         let conference_name_clone = conference_name.to_string();
         let start_time_clone = start_time;
@@ -868,7 +2121,8 @@ impl WaitlistQueueService {
                 // Calculate delay until conference starts
                 let now = Utc::now();
                 let delay_seconds = (start_time - now).num_seconds();
-                
+                tracing::Span::current().record("ttl_seconds", delay_seconds.max(0));
+
                 if delay_seconds > 0 {
                     info!("📅 Scheduled conference start event for '{}' at {} (in {} seconds)", 
                           conference_name, start_time, delay_seconds);
@@ -885,51 +2139,51 @@ impl WaitlistQueueService {
                         )
                         .await?;
                     
-                    // Now set up the shared timer queue with dead letter routing
-                    let timer_queue_name = "conference.start.timer";
-                    let mut args = FieldTable::new();
-                    args.insert(
-                        "x-dead-letter-exchange".try_into()?,
-                        "".into() // Route to default exchange
-                    );
-                    args.insert(
-                        "x-dead-letter-routing-key".try_into()?,
-                        service.conference_start_queue.clone().into()
-                    );
-                    
-                    // Declare the shared timer queue (idempotent - safe to call multiple times)
-                    channel
-                        .queue_declare(
-                            QueueDeclareArguments::new(timer_queue_name)
-                                .durable(true)
-                                .arguments(args)
-                                .finish(),
-                        )
-                        .await?;
-                    
+                    let timer_queue_name = service.conference_start_timer_queue.as_str();
+                    if service.delay_strategy == DelayStrategy::DlxTtl {
+                        // Set up the shared timer queue with dead letter routing
+                        let mut args = FieldTable::new();
+                        args.insert(
+                            "x-dead-letter-exchange".try_into()?,
+                            "".into() // Route to default exchange
+                        );
+                        args.insert(
+                            "x-dead-letter-routing-key".try_into()?,
+                            service.conference_start_queue.clone().into()
+                        );
+
+                        // Declare the shared timer queue (idempotent - safe to call multiple times)
+                        channel
+                            .queue_declare(
+                                QueueDeclareArguments::new(timer_queue_name)
+                                    .durable(true)
+                                    .arguments(args)
+                                    .finish(),
+                            )
+                            .await?;
+                    }
+
                     // Create the conference start message
                     let start_msg = ConferenceStartMessage {
                         conference_name: conference_name.clone(),
                         start_time,
                     };
-                    
-                    // Publish message to shared timer queue with TTL = delay in milliseconds
+
+                    // Schedule it for delivery to the conference-start queue after
+                    // `delay_seconds`, via whichever `DelayStrategy` is configured.
                     let serialized = serde_json::to_string(&start_msg)?;
                     let content = serialized.as_bytes().to_vec();
-                    
-                    let ttl_ms = (delay_seconds * 1000).max(1); // At least 1ms
-                    let properties = BasicProperties::default()
-                        .with_delivery_mode(2) // persistent
-                        .with_expiration(&ttl_ms.to_string()) // TTL in milliseconds
-                        .finish();
-                    
-                    let args = BasicPublishArguments::new("", timer_queue_name);
-                    
-                    channel.basic_publish(properties, content, args).await?;
-                    
-                    info!("⏰ Published conference start timer message for '{}' with TTL {}ms to shared queue", conference_name, ttl_ms);
-                    info!("🔀 Message will be dead-lettered to '{}' queue when TTL expires", service.conference_start_queue);
-                    
+
+                    let delay_ms = (delay_seconds * 1000).max(1); // At least 1ms
+                    let mut headers = FieldTable::new();
+                    crate::telemetry::inject_trace_context(&mut headers);
+                    service
+                        .publish_delayed(&channel, timer_queue_name, &service.conference_start_queue, delay_ms, content, headers)
+                        .await?;
+
+                    info!("⏰ Scheduled conference start message for '{}' with a {}ms delay", conference_name, delay_ms);
+                    info!("🔀 Message will reach the '{}' queue once the delay elapses", service.conference_start_queue);
+
                     let _ = channel.close().await;
                 } else {
                     // Conference start time has passed, trigger immediately
@@ -941,11 +2195,14 @@ impl WaitlistQueueService {
                     let channel = service.get_fresh_channel().await?;
                     let serialized = serde_json::to_string(&start_msg)?;
                     let content = serialized.as_bytes().to_vec();
-                    
+
+                    let mut headers = FieldTable::new();
+                    crate::telemetry::inject_trace_context(&mut headers);
                     let properties = BasicProperties::default()
                         .with_delivery_mode(2) // persistent
+                        .with_headers(headers)
                         .finish();
-                    
+
                     let args = BasicPublishArguments::new("", &service.conference_start_queue);
                     
                     channel.basic_publish(properties, content, args).await?;
@@ -959,7 +2216,7 @@ impl WaitlistQueueService {
             }
         };
         
-        self.safe_queue_operation(operation).await
+        self.safe_queue_operation("schedule_conference_start_event", operation).await
     }
 }
 
@@ -978,6 +2235,25 @@ impl Clone for WaitlistQueueService {
             dead_letter_exchange: self.dead_letter_exchange.clone(),
             dead_letter_queue: self.dead_letter_queue.clone(),
             conference_start_queue: self.conference_start_queue.clone(),
+            conference_start_timer_queue: self.conference_start_timer_queue.clone(),
+            delay_strategy: self.delay_strategy,
+            delayed_exchange: self.delayed_exchange.clone(),
+            confirmation_deadline_secs: self.confirmation_deadline_secs,
+            conference_waitlist_capacity: self.conference_waitlist_capacity,
+            max_redeliveries: self.max_redeliveries,
+            consumer_prefetch_count: self.consumer_prefetch_count,
+            mqtt_broker: self.mqtt_broker.clone(),
+            mqtt: self.mqtt.clone(),
+            notifier: self.notifier.clone(),
+            metrics: self.metrics.clone(),
+            publish_rate_limiter: self.publish_rate_limiter.clone(),
+            otlp_endpoint: self.otlp_endpoint.clone(),
+            amqp_host: self.amqp_host.clone(),
+            amqp_port: self.amqp_port,
+            amqp_username: self.amqp_username.clone(),
+            amqp_password: self.amqp_password.clone(),
+            booking_events: self.booking_events.clone(),
+            cache: self.cache.clone(),
         }
     }
 }
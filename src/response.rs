@@ -0,0 +1,153 @@
+// Shared response envelope and error type for the booking endpoints
+// (`/book`, `/book/resource`, `/confirm`, `/cancel`, `/booking/{id}`, and the
+// resource-availability lookup), which used to each build their own success
+// struct (`BookConferenceResponse`, `BookingStatusResponse`, the old
+// `models::ApiResponse { message }`) and their own `error::InternalError`
+// mapping. `ApiResponse<T>` wraps every success payload in a uniform
+// `{ success, message, data }` envelope, and `ApiError` gives callers a
+// machine-readable `code` for cases like "waitlist full" or "confirmation
+// deadline expired" instead of matching on message text. The streaming
+// endpoints (`/booking/{id}/events`, `/booking/{id}/ws`) aren't part of this -
+// they don't speak JSON to begin with.
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ApiError {
+    NotFound(String),
+    Unauthorized(String),
+    Conflict(String),
+    OverCapacity(String),
+    DeadlinePassed(String),
+    BadStatusTransition(String),
+    BadRequest(String),
+    Internal(String),
+}
+
+impl ApiError {
+    fn code(&self) -> &'static str {
+        match self {
+            ApiError::NotFound(_) => "not_found",
+            ApiError::Unauthorized(_) => "unauthorized",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::OverCapacity(_) => "over_capacity",
+            ApiError::DeadlinePassed(_) => "deadline_passed",
+            ApiError::BadStatusTransition(_) => "bad_status_transition",
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Internal(_) => "internal_error",
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            ApiError::NotFound(m)
+            | ApiError::Unauthorized(m)
+            | ApiError::Conflict(m)
+            | ApiError::OverCapacity(m)
+            | ApiError::DeadlinePassed(m)
+            | ApiError::BadStatusTransition(m)
+            | ApiError::BadRequest(m)
+            | ApiError::Internal(m) => m,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            ApiError::Conflict(_) | ApiError::OverCapacity(_) | ApiError::BadStatusTransition(_) => {
+                StatusCode::CONFLICT
+            }
+            ApiError::DeadlinePassed(_) => StatusCode::GONE,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ApiResponse::<()>::err(self.code(), self.message()))
+    }
+}
+
+// Classifies the opaque `Box<dyn Error + Send + Sync>` that `web::block`
+// closures return (diesel errors, and the ad hoc `&str`/`String` errors the
+// booking actions return for things like "already has an active booking") into the
+// matching `ApiError` variant, so handlers can just `.map_err(ApiError::from)?`
+// instead of re-deriving the status code at every call site.
+impl From<Box<dyn std::error::Error + Send + Sync>> for ApiError {
+    fn from(e: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        let detail = e.to_string();
+
+        if let Some(diesel_error) = e.downcast_ref::<diesel::result::Error>() {
+            return match diesel_error {
+                diesel::result::Error::NotFound => ApiError::NotFound(detail),
+                diesel::result::Error::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _) => {
+                    ApiError::Conflict(detail)
+                }
+                _ => ApiError::BadRequest(detail),
+            };
+        }
+
+        if detail.contains("does not belong") || detail.starts_with("Access denied") {
+            ApiError::Unauthorized(detail)
+        } else if detail.contains("overlapping") || detail.contains("already has an active booking") {
+            ApiError::Conflict(detail)
+        } else if detail.contains("waitlist is full") {
+            ApiError::OverCapacity(detail)
+        } else if detail.contains("already started") || detail.contains("deadline has expired") {
+            ApiError::DeadlinePassed(detail)
+        } else if detail.contains("not in confirmation pending state") || detail.contains("cannot be confirmed") {
+            ApiError::BadStatusTransition(detail)
+        } else {
+            ApiError::BadRequest(detail)
+        }
+    }
+}
+
+impl From<actix_web::error::BlockingError> for ApiError {
+    fn from(e: actix_web::error::BlockingError) -> Self {
+        ApiError::Internal(e.to_string())
+    }
+}
+
+// `{ success, message, data }` envelope every booking endpoint responds
+// with. `data` is `None` on the error path - see `ApiError::error_response`,
+// which builds an `ApiResponse<()>` of its own rather than going through
+// `ok`/`Responder` below.
+#[derive(Debug, Serialize)]
+pub struct ApiResponse<T: Serialize> {
+    pub success: bool,
+    pub message: String,
+    pub data: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'static str>,
+}
+
+impl<T: Serialize> ApiResponse<T> {
+    pub fn ok(message: impl Into<String>, data: T) -> Self {
+        Self { success: true, message: message.into(), data: Some(data), code: None }
+    }
+}
+
+impl ApiResponse<()> {
+    fn err(code: &'static str, message: &str) -> Self {
+        Self { success: false, message: message.to_string(), data: None, code: Some(code) }
+    }
+}
+
+impl<T: Serialize> actix_web::Responder for ApiResponse<T> {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _req: &actix_web::HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok().json(self)
+    }
+}
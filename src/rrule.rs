@@ -0,0 +1,117 @@
+// Minimal iCalendar RRULE (RFC 5545) expansion - just enough to cover
+// `add_conference`'s recurring-conference use case. Supports `FREQ` of
+// DAILY/WEEKLY/MONTHLY, an optional `INTERVAL`, and a bounding `COUNT` or
+// `UNTIL`. Anything else (BYDAY, BYSETPOS, WKST, ...) is rejected rather than
+// silently ignored, since partially honoring a rule would be worse than
+// refusing it.
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+// Expands `rule` into occurrence start timestamps beginning at `dtstart` (the
+// first occurrence), capped at `max_occurrences` regardless of what COUNT/UNTIL
+// say - protects against a rule like `FREQ=DAILY` with an UNTIL years away.
+// A rule with neither COUNT nor UNTIL is rejected.
+pub fn expand_occurrences(rule: &str, dtstart: NaiveDateTime, max_occurrences: usize) -> Result<Vec<NaiveDateTime>, String> {
+    let mut freq: Option<Freq> = None;
+    let mut interval: i64 = 1;
+    let mut count: Option<usize> = None;
+    let mut until: Option<NaiveDateTime> = None;
+
+    for part in rule.split(';') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+
+        let (key, value) = part.split_once('=').ok_or_else(|| format!("malformed RRULE part: {}", part))?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                freq = Some(match value.to_ascii_uppercase().as_str() {
+                    "DAILY" => Freq::Daily,
+                    "WEEKLY" => Freq::Weekly,
+                    "MONTHLY" => Freq::Monthly,
+                    other => return Err(format!("unsupported FREQ: {}", other)),
+                });
+            }
+            "INTERVAL" => {
+                interval = value.parse::<i64>().map_err(|_| format!("invalid INTERVAL: {}", value))?;
+                if interval <= 0 {
+                    return Err("INTERVAL must be positive".to_string());
+                }
+            }
+            "COUNT" => {
+                count = Some(value.parse::<usize>().map_err(|_| format!("invalid COUNT: {}", value))?);
+            }
+            "UNTIL" => {
+                until = Some(
+                    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+                        .map_err(|_| format!("invalid UNTIL: {}", value))?,
+                );
+            }
+            other => return Err(format!("unsupported RRULE part: {}", other)),
+        }
+    }
+
+    let freq = freq.ok_or("RRULE must specify FREQ")?;
+
+    if count.is_none() && until.is_none() {
+        return Err("RRULE must specify COUNT or UNTIL".to_string());
+    }
+
+    let limit = count.map(|c| c.min(max_occurrences)).unwrap_or(max_occurrences);
+
+    let mut occurrences = Vec::new();
+    let mut n: i64 = 0;
+
+    while occurrences.len() < limit {
+        let current = match freq {
+            Freq::Daily => dtstart + Duration::days(n * interval),
+            Freq::Weekly => dtstart + Duration::weeks(n * interval),
+            Freq::Monthly => add_months(dtstart, n * interval),
+        };
+
+        if let Some(until) = until {
+            if current > until {
+                break;
+            }
+        }
+
+        occurrences.push(current);
+        n += 1;
+    }
+
+    if occurrences.is_empty() {
+        return Err("RRULE expanded to zero occurrences".to_string());
+    }
+
+    Ok(occurrences)
+}
+
+// Adds `months` calendar months to `base`, clamping the day-of-month into the
+// target month (e.g. Jan 31 + 1 month -> Feb 28/29) instead of overflowing
+// into the month after.
+fn add_months(base: NaiveDateTime, months: i64) -> NaiveDateTime {
+    let total_months = base.month0() as i64 + months;
+    let year = base.year() + total_months.div_euclid(12) as i32;
+    let month = total_months.rem_euclid(12) as u32 + 1;
+    let day = base.day().min(last_day_of_month(year, month));
+
+    NaiveDate::from_ymd_opt(year, month, day).unwrap().and_time(base.time())
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let next_month_first = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .unwrap();
+
+    next_month_first.pred_opt().unwrap().day()
+}
@@ -21,6 +21,9 @@ diesel::table! {
         canceled_at -> Nullable<Timestamp>,
         can_confirm -> Nullable<Bool>,
         waitlist_position -> Nullable<Int4>,
+        reminder_sent_at -> Nullable<Timestamp>,
+        resource_id -> Nullable<Int4>,
+        slot_start -> Nullable<Timestamp>,
     }
 }
 
@@ -47,6 +50,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    resources (resource_id) {
+        resource_id -> Int4,
+        conference_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        granularity_minutes -> Int4,
+        capacity -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     user_interests (user_id, topic) {
         #[max_length = 255]
@@ -61,18 +76,23 @@ diesel::table! {
         #[max_length = 255]
         user_id -> Varchar,
         created_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
     }
 }
 
 diesel::joinable!(bookings -> conferences (conference_id));
 diesel::joinable!(bookings -> users (user_id));
+diesel::joinable!(bookings -> resources (resource_id));
 diesel::joinable!(conference_topics -> conferences (conference_id));
+diesel::joinable!(resources -> conferences (conference_id));
 diesel::joinable!(user_interests -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     bookings,
     conference_topics,
     conferences,
+    resources,
     user_interests,
     users,
 );
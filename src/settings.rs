@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+// Operational settings for `WaitlistQueueService`, loaded the way
+// nostr-rs-relay's `Settings` is: a `config.toml` in the working directory,
+// layered with environment variable overrides, falling back to `Default`
+// for anything neither source sets. This lets the crate be redeployed with
+// different timers/queue names/pool sizes without a recompile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // How long a promoted booking has to confirm before its slot is
+    // released back to the waitlist. Threaded into `Duration::seconds(..)`
+    // and the AMQP message TTL (in milliseconds) for the confirmation timer.
+    pub confirmation_deadline_secs: i64,
+    pub conference_exchange: String,
+    pub booking_exchange: String,
+    pub waitlist_queue_prefix: String,
+    pub confirmation_timer_queue: String,
+    pub dead_letter_exchange: String,
+    pub dead_letter_queue: String,
+    pub conference_start_queue: String,
+    pub conference_start_timer_queue: String,
+    // Size of `WaitlistQueueService`'s pooled-channel `Vec`.
+    pub max_channels: usize,
+    // How `publish_slot_available`/`schedule_conference_start_event` delay a
+    // message's delivery. See `DelayStrategy`.
+    pub delay_strategy: DelayStrategy,
+    // `x-delayed-message` exchange used when `delay_strategy` is
+    // `DelayedExchange`.
+    pub delayed_exchange: String,
+    // Address `HttpServer` binds to.
+    pub http_host: String,
+    pub http_port: u16,
+    // Size of the r2d2 Postgres connection pool shared across HTTP workers.
+    pub db_pool_max_size: u32,
+    // RabbitMQ connection details passed to `amqprs::connection::Connection::open`.
+    pub amqp_host: String,
+    pub amqp_port: u16,
+    pub amqp_username: String,
+    pub amqp_password: String,
+    // Max topics allowed on a single conference/user, enforced by `add_conference`
+    // / `add_user` and (for conferences) re-checked in
+    // `actions::create_recurring_conferences`.
+    pub max_conference_topics: i32,
+    pub max_user_topics: i32,
+    // Capacity of `cache::LookupCache`'s conference-by-name and
+    // booking-by-id LRUs (each sized independently to this many entries).
+    pub cache_capacity: usize,
+    // HS256 signing secret for `auth::issue_token`/`auth::AuthenticatedUser`.
+    // Must be overridden via `APP__JWT_SECRET` outside of local development -
+    // the default here is only good enough to boot without a `config.toml`.
+    pub jwt_secret: String,
+    // How long a token issued by `/user`/`/login` remains valid before
+    // `auth::AuthenticatedUser` rejects it as expired.
+    pub jwt_ttl_secs: i64,
+    // SMTP relay `notifier::SmtpNotifier` delivers the "slot available" and
+    // confirmation-deadline reminder messages through. An empty host
+    // disables it entirely - the same convention `mqtt_broker: Option<...>`
+    // being `None` uses to disable the MQTT push bridge - since a
+    // notification is always best-effort and should never block the
+    // booking-state change that triggers it.
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: String,
+    pub smtp_password: String,
+    pub smtp_from: String,
+    // Webhook URL for the same two events. Nothing reads it: the only
+    // `notifier::Notifier` implementation is `SmtpNotifier`, which sends
+    // email only and never calls a webhook.
+    pub webhook_url: Option<String>,
+    // How long before `waitlist_confirmation_deadline` the reminder
+    // scheduler sends its one follow-up reminder.
+    pub confirmation_reminder_lead_secs: i64,
+    // How often `notifier::run_confirmation_reminder_scheduler` polls for
+    // bookings that have entered that window.
+    pub confirmation_reminder_poll_interval_secs: u64,
+}
+
+// Strategy for delaying delivery of a confirmation-expiry or
+// conference-start message.
+//
+// `DlxTtl` is the original approach: publish to a shared timer queue with a
+// per-message `x-expiration`, dead-lettering back to the destination queue
+// once it expires. RabbitMQ only expires messages in head-of-queue order, so
+// a short delay queued behind an earlier long one is blocked until the long
+// one expires or is consumed.
+//
+// `DelayedExchange` avoids that by publishing straight to an
+// `x-delayed-message` exchange (the community RabbitMQ delayed-message
+// plugin) with an `x-delay` header in milliseconds; the plugin tracks each
+// message's delay independently, so there's no head-of-line blocking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DelayStrategy {
+    DlxTtl,
+    DelayedExchange,
+}
+
+impl Default for DelayStrategy {
+    fn default() -> Self {
+        DelayStrategy::DlxTtl
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            confirmation_deadline_secs: 10,
+            conference_exchange: "conference.events".to_string(),
+            booking_exchange: "booking.events".to_string(),
+            waitlist_queue_prefix: "conference.".to_string(),
+            confirmation_timer_queue: "confirmation.timer".to_string(),
+            dead_letter_exchange: "dead.letter.exchange".to_string(),
+            dead_letter_queue: "confirmation.expired".to_string(),
+            conference_start_queue: "conference.starts".to_string(),
+            conference_start_timer_queue: "conference.start.timer".to_string(),
+            max_channels: 10, // Smaller pool for stability
+            delay_strategy: DelayStrategy::DlxTtl,
+            delayed_exchange: "waitlist.delayed".to_string(),
+            http_host: "127.0.0.1".to_string(),
+            http_port: 8080,
+            db_pool_max_size: 10,
+            amqp_host: "localhost".to_string(),
+            amqp_port: 5672,
+            amqp_username: "guest".to_string(),
+            amqp_password: "guest".to_string(),
+            max_conference_topics: 10,
+            max_user_topics: 50,
+            cache_capacity: 1000,
+            jwt_secret: "dev-secret-change-me".to_string(),
+            jwt_ttl_secs: 60 * 60 * 24,
+            smtp_host: String::new(),
+            smtp_port: 587,
+            smtp_username: String::new(),
+            smtp_password: String::new(),
+            smtp_from: "waitlist@localhost".to_string(),
+            webhook_url: None,
+            confirmation_reminder_lead_secs: 60 * 5,
+            confirmation_reminder_poll_interval_secs: 30,
+        }
+    }
+}
+
+impl Settings {
+    // Loads `config.toml` (if present) layered with `APP__`-prefixed,
+    // double-underscore-separated environment variable overrides (e.g.
+    // `APP__CONFIRMATION_DEADLINE_SECS=30`), falling back to `Default` for
+    // anything neither source sets.
+    pub fn load() -> Result<Self, config::ConfigError> {
+        config::Config::builder()
+            .add_source(config::Config::try_from(&Settings::default())?)
+            .add_source(config::File::with_name("config").required(false))
+            .add_source(config::Environment::with_prefix("APP").separator("__"))
+            .build()?
+            .try_deserialize()
+    }
+}
@@ -0,0 +1,101 @@
+use amqprs::{BasicProperties, FieldTable, FieldValue};
+use opentelemetry::global;
+use opentelemetry::propagation::{Extractor, Injector};
+use std::collections::HashMap;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+// Initializes the tracing subscriber that backs every `#[tracing::instrument]`
+// span in the service. It also tries to install `tracing_log::LogTracer` to
+// bridge `log::info!`/`error!` call sites into the same pipeline; when a `log`
+// logger is already installed (as `main` does with `env_logger` before this
+// runs), that step fails silently and `log` output keeps going through the
+// existing logger only, not into spans. When `otlp_endpoint`
+// is set, spans are also exported over OTLP; otherwise they're only printed
+// via the `fmt` layer.
+pub fn init(otlp_endpoint: Option<&str>) {
+    use tracing_subscriber::prelude::*;
+
+    let _ = tracing_log::LogTracer::init();
+
+    // Register the W3C trace-context propagator globally so
+    // `inject_trace_context`/`extract_trace_context` actually carry a
+    // `traceparent` through AMQP headers instead of the no-op default.
+    global::set_text_map_propagator(opentelemetry_sdk::propagation::TraceContextPropagator::new());
+
+    let otel_layer = otlp_endpoint.map(|endpoint| {
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint))
+            .install_batch(opentelemetry_sdk::runtime::Tokio)
+            .expect("OTLP tracer pipeline should install");
+        tracing_opentelemetry::layer().with_tracer(tracer)
+    });
+
+    let _ = tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .with(otel_layer)
+        .try_init();
+}
+
+// A plain owned-string carrier for OpenTelemetry's `Injector`/`Extractor`
+// traits, used as the intermediate form between a span's context and an
+// amqprs `FieldTable` (whose header values aren't guaranteed to hand back a
+// borrowed `&str` the way the trait methods require).
+struct MapCarrier(HashMap<String, String>);
+
+impl Injector for MapCarrier {
+    fn set(&mut self, key: &str, value: String) {
+        self.0.insert(key.to_string(), value);
+    }
+}
+
+impl Extractor for MapCarrier {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(|v| v.as_str())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
+
+// Injects the current span's W3C `traceparent` (and any baggage) into
+// `headers`, so the next queue hop - consumed by `ExpiredConfirmationConsumer`
+// or `ConferenceStartConsumer` - can continue the same distributed trace.
+pub fn inject_trace_context(headers: &mut FieldTable) {
+    let mut carrier = MapCarrier(HashMap::new());
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&tracing::Span::current().context(), &mut carrier)
+    });
+
+    for (key, value) in carrier.0 {
+        if let Ok(short_str) = key.try_into() {
+            headers.insert(short_str, value.into());
+        }
+    }
+}
+
+// Extracts a parent `opentelemetry::Context` from a delivery's headers, if a
+// `traceparent` was injected by `inject_trace_context` upstream. Returns the
+// (empty) root context otherwise, so the consumer's span just starts fresh.
+pub fn extract_trace_context(headers: Option<&FieldTable>) -> opentelemetry::Context {
+    let mut map = HashMap::new();
+
+    if let Some(headers) = headers {
+        for (key, value) in headers.iter() {
+            if let FieldValue::LongString(s) = value {
+                map.insert(key.to_string(), s.to_string());
+            }
+        }
+    }
+
+    let carrier = MapCarrier(map);
+    global::get_text_map_propagator(|propagator| propagator.extract(&carrier))
+}
+
+// Sets `span`'s parent to the trace context carried in `headers`, if any.
+// Called at the top of each consumer's `consume` before instrumenting the
+// handler future with `span`.
+pub fn set_parent_from_headers(span: &tracing::Span, headers: Option<&FieldTable>) {
+    span.set_parent(extract_trace_context(headers));
+}